@@ -0,0 +1,31 @@
+use futures::TryStreamExt;
+use polygon_io_client_rust::aggregates::{AggregatesRequest, Timespan};
+use polygon_io_client_rust::pagination::PaginationOptions;
+use polygon_io_client_rust::polygon_client::PolygonClientBuilder;
+use time::macros::date;
+
+#[tokio::main]
+async fn main() {
+    let poly_client = PolygonClientBuilder::new().auth_key_env("POLYGON_AUTH_KEY").build().unwrap();
+
+    let request = AggregatesRequest::new()
+        .ticker("AAPL")
+        .range(15, Timespan::Minute)
+        .from(date!(2023 - 01 - 09))
+        .to(date!(2023 - 01 - 13));
+
+    println!("Request: {:#?}", request);
+
+    let bars: Vec<_> = poly_client
+        .aggregates_stream(&request, PaginationOptions::new())
+        .try_collect()
+        .await
+        .unwrap();
+
+    for bar in bars {
+        println!(
+            "{} o: {} h: {} l: {} c: {} v: {}",
+            bar.timestamp, bar.open, bar.high, bar.low, bar.close, bar.volume
+        );
+    }
+}
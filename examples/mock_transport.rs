@@ -0,0 +1,22 @@
+use polygon_io_client_rust::polygon_client::PolygonClientBuilder;
+use polygon_io_client_rust::ticker_details::TickerDetailsRequest;
+use polygon_io_client_rust::transport::MockTransport;
+
+#[tokio::main]
+async fn main() {
+    let transport = MockTransport::new().with_response(
+        "/v3/reference/tickers/AAPL",
+        &[],
+        r#"{"request_id":"1","status":"OK","results":{"ticker":"AAPL","name":"Apple Inc.","active":true}}"#,
+    );
+
+    let poly_client = PolygonClientBuilder::new().transport(transport).build().unwrap();
+
+    let request = TickerDetailsRequest::new().ticker("AAPL");
+    let results = poly_client.get_tickers_details(&request).await;
+    println!("results: {results:#?}\n");
+
+    let request = TickerDetailsRequest::new().ticker("MSFT");
+    let results = poly_client.get_tickers_details(&request).await;
+    println!("results: {results:#?}\n");
+}
@@ -0,0 +1,21 @@
+use futures::TryStreamExt;
+use polygon_io_client_rust::pagination::PaginationOptions;
+use polygon_io_client_rust::polygon_client::PolygonClientBuilder;
+use polygon_io_client_rust::tickers::{Market, TickersRequest};
+
+#[tokio::main]
+async fn main() {
+    let poly_client = PolygonClientBuilder::new().auth_key_env("POLYGON_AUTH_KEY").build().unwrap();
+
+    let request = TickersRequest::new().market(Market::Stocks).limit(1000);
+
+    println!("Request: {:#?}", request);
+
+    let options = PaginationOptions::new().max_pages(3);
+    let tickers: Result<Vec<_>, _> = poly_client.tickers_stream(&request, options).try_collect().await;
+
+    match tickers {
+        Ok(tickers) => println!("fetched {} tickers", tickers.len()),
+        Err(e) => println!("error: {}", e),
+    }
+}
@@ -0,0 +1,231 @@
+use crate::common::{Money, Order};
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::utils::{millis_format, money_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::{Date, OffsetDateTime};
+
+/// /v2/aggs/ticker/{ticker}/range/{multiplier}/{timespan}/{from}/{to}
+/// Get aggregate bars for a ticker over a given date range in custom time window sizes.
+const AGGREGATES_PATH: &str = "/v2/aggs/ticker/{ticker}/range/{multiplier}/{timespan}/{from}/{to}";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AggregatesResponse {
+    /// The exchange symbol that this item is traded under.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// Whether or not this response was adjusted for splits.
+    #[serde(default = "bool::default")]
+    pub adjusted: bool,
+    /// The number of aggregates (minute or day) used to generate the response.
+    #[serde(rename = "queryCount", default = "u64::default")]
+    pub query_count: u64,
+    /// The total number of results for this request.
+    #[serde(rename = "resultsCount", default = "u64::default")]
+    pub results_count: u64,
+    /// If present, this value can be used to fetch the next page of data.
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// The bars of the requested range. Not sent when there is no data for the range.
+    #[serde(default = "Vec::default")]
+    pub results: Vec<Bar>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl Paginated for AggregatesResponse {
+    type Item = Bar;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<Bar> {
+        self.results
+    }
+}
+
+/// An aggregate bar, the prices and volume of a ticker over one window of the requested timespan.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Bar {
+    /// The open price for the symbol in the given time period.
+    #[serde(rename = "o", with = "money_format")]
+    pub open: Money,
+    /// The highest price for the symbol in the given time period.
+    #[serde(rename = "h", with = "money_format")]
+    pub high: Money,
+    /// The lowest price for the symbol in the given time period.
+    #[serde(rename = "l", with = "money_format")]
+    pub low: Money,
+    /// The close price for the symbol in the given time period.
+    #[serde(rename = "c", with = "money_format")]
+    pub close: Money,
+    /// The trading volume of the symbol in the given time period. Fractional for crypto.
+    #[serde(rename = "v")]
+    pub volume: f64,
+    /// The volume weighted average price.
+    #[serde(rename = "vw", default, with = "money_format::option")]
+    pub vwap: Option<Money>,
+    /// The number of transactions in the aggregate window.
+    #[serde(rename = "n", default)]
+    pub transactions: Option<u64>,
+    /// The start of the aggregate window.
+    #[serde(rename = "t", with = "millis_format")]
+    pub timestamp: OffsetDateTime,
+    /// Whether or not this aggregate is for an OTC ticker.
+    #[serde(default = "bool::default")]
+    pub otc: bool,
+}
+
+/// A bar together with the ticker it belongs to, returned by endpoints covering more than one ticker or session.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerBar {
+    /// The exchange symbol that this item is traded under.
+    #[serde(rename = "T")]
+    pub ticker: String,
+    #[serde(flatten)]
+    pub bar: Bar,
+}
+
+/// The size of the time window of a bar, multiplied by the multiplier of the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timespan {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl fmt::Display for Timespan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct AggregatesRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+    pub multiplier: u32,
+    pub timespan: Timespan,
+    pub from: Option<Date>,
+    pub to: Option<Date>,
+}
+
+impl AggregatesRequest {
+    /// Daily bars unless set with [range](Self::range). The ticker, `from` and `to` must be set.
+    pub fn new() -> Self {
+        AggregatesRequest {
+            path: AGGREGATES_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+            multiplier: 1,
+            timespan: Timespan::Day,
+            from: None,
+            to: None,
+        }
+    }
+
+    /// The ticker symbol of the asset.
+    pub fn ticker(mut self, ticker: impl Into<String>) -> AggregatesRequest {
+        self.ticker = ticker.into();
+        self
+    }
+
+    /// The size of the time window, e.g. `range(5, Timespan::Minute)` for 5-minute bars.
+    pub fn range(mut self, multiplier: u32, timespan: Timespan) -> AggregatesRequest {
+        self.multiplier = multiplier;
+        self.timespan = timespan;
+        self
+    }
+
+    /// The first day of the requested range.
+    pub fn from(mut self, from: Date) -> AggregatesRequest {
+        self.from = Some(from);
+        self
+    }
+
+    /// The last day of the requested range, included in the results.
+    pub fn to(mut self, to: Date) -> AggregatesRequest {
+        self.to = Some(to);
+        self
+    }
+
+    /// Whether or not the results are adjusted for splits. By default, results are adjusted.
+    pub fn adjusted(mut self, adjusted: bool) -> AggregatesRequest {
+        self.parameters.insert("adjusted".to_string(), adjusted.to_string());
+        self
+    }
+
+    /// Sort the results by timestamp. `Asc` returns the oldest bar first, `Desc` the newest.
+    pub fn sort(mut self, sort: Order) -> AggregatesRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+
+    /// Limits the number of base aggregates queried to create the aggregate results, default is 5000 and max is 50000.
+    pub fn limit(mut self, limit: u32) -> AggregatesRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+}
+
+impl Default for AggregatesRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for AggregatesRequest {
+    fn get_url(&self) -> String {
+        let date = |date: Option<Date>| date.map(|date| date.to_string()).unwrap_or_default();
+        format!(
+            "/v2/aggs/ticker/{}/range/{}/{}/{}/{}",
+            self.ticker,
+            self.multiplier,
+            self.timespan,
+            date(self.from),
+            date(self.to)
+        )
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)?;
+        validation::limit(&self.parameters, 50000)?;
+
+        if self.multiplier == 0 {
+            return Err(ValidationError::InvalidValue {
+                field: "multiplier".to_string(),
+                value: self.multiplier.to_string(),
+            });
+        }
+
+        match (self.from, self.to) {
+            (None, _) => Err(ValidationError::MissingPathParameter("from")),
+            (_, None) => Err(ValidationError::MissingPathParameter("to")),
+            (Some(from), Some(to)) if from > to => Err(ValidationError::InvertedRange {
+                field: "from/to".to_string(),
+                lower: from.to_string(),
+                upper: to.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
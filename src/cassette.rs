@@ -0,0 +1,125 @@
+use crate::error::PolygonError;
+use crate::transport::{HttpRequest, HttpResponse, Transport};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// A recorded request and its response, stored as one json file per request.
+/// The auth key is not recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Path and sorted query of the request, see `HttpRequest::key`.
+    pub request: String,
+    pub status: u16,
+    /// The response body, stored as json so cassettes are readable. Bodies which are not json are stored as a string.
+    pub body: serde_json::Value,
+}
+
+impl Cassette {
+    fn new(request: &HttpRequest, response: &HttpResponse) -> Self {
+        Cassette {
+            request: request.key(),
+            status: response.status,
+            body: serde_json::from_str(&response.body).unwrap_or_else(|_| serde_json::Value::String(response.body.clone())),
+        }
+    }
+
+    fn response(&self) -> HttpResponse {
+        let body = match &self.body {
+            serde_json::Value::String(body) => body.clone(),
+            body => body.to_string(),
+        };
+        HttpResponse::new(self.status, body)
+    }
+
+    /// Name of the file the cassette of a request is stored in.
+    /// Readable part of the key, followed by a hash of the whole key to keep names unique.
+    fn file_name(key: &str) -> String {
+        let readable: String = key
+            .trim_start_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+            .take(100)
+            .collect();
+        format!("{}-{:016x}.json", readable, fnv1a(key))
+    }
+}
+
+/// Stable 64 bit FNV-1a hash, so cassette file names don't change between builds.
+fn fnv1a(key: &str) -> u64 {
+    key.bytes()
+        .fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Transport which sends requests through another transport and writes every request and response to a directory.
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    dir: PathBuf,
+}
+
+impl RecordingTransport {
+    pub fn new(inner: Arc<dyn Transport>, dir: impl Into<PathBuf>) -> Result<Self, PolygonError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| PolygonError::Cassette(format!("failed to create {}: {}", dir.display(), e)))?;
+        Ok(RecordingTransport { inner, dir })
+    }
+
+    fn record(&self, cassette: &Cassette) -> Result<(), PolygonError> {
+        let path = self.dir.join(Cassette::file_name(&cassette.request));
+        let json = serde_json::to_string_pretty(cassette).map_err(|e| PolygonError::Cassette(e.to_string()))?;
+        fs::write(&path, json).map_err(|e| PolygonError::Cassette(format!("failed to write {}: {}", path.display(), e)))
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse, PolygonError>> {
+        Box::pin(async move {
+            let response = self.inner.send(request).await?;
+            self.record(&Cassette::new(request, &response))?;
+            Ok(response)
+        })
+    }
+}
+
+/// Transport which answers requests from the cassettes in a directory without network access.
+/// Requests without a cassette fail with `PolygonError::Cassette`.
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    cassettes: HashMap<String, Cassette>,
+}
+
+impl ReplayTransport {
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, PolygonError> {
+        let dir = dir.as_ref();
+        let entries = fs::read_dir(dir).map_err(|e| PolygonError::Cassette(format!("failed to read {}: {}", dir.display(), e)))?;
+
+        let mut cassettes = HashMap::new();
+        for entry in entries {
+            let path = entry.map_err(|e| PolygonError::Cassette(e.to_string()))?.path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            let json = fs::read_to_string(&path).map_err(|e| PolygonError::Cassette(format!("failed to read {}: {}", path.display(), e)))?;
+            let cassette: Cassette =
+                serde_json::from_str(&json).map_err(|e| PolygonError::Cassette(format!("invalid cassette {}: {}", path.display(), e)))?;
+            cassettes.insert(cassette.request.clone(), cassette);
+        }
+
+        Ok(ReplayTransport { cassettes })
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse, PolygonError>> {
+        let key = request.key();
+        let response = match self.cassettes.get(&key) {
+            Some(cassette) => Ok(cassette.response()),
+            None => Err(PolygonError::Cassette(format!("no cassette recorded for {}", key))),
+        };
+        Box::pin(async move { response })
+    }
+}
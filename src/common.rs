@@ -1,15 +1,79 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Formatter;
 
-#[derive(Debug)]
-pub enum Locale {
-    US,
-    Global,
+/// Defines an enum for a string field of the api, listing each variant with the value it is sent as.
+/// Values the api doesn't document, or which were added after this client was written, deserialize to an added
+/// `Unknown` variant holding the value instead of failing the whole response. The enum gets `as_str`, `Display`,
+/// a `Default` of `Unknown("")` and serde conversions through `String`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+        #[serde(from = "String", into = "String")]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::Unknown(String::new())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+    };
 }
 
-impl fmt::Display for Locale {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+pub(crate) use string_enum;
+
+/// A monetary amount, `rust_decimal::Decimal` with the `decimal` feature enabled and `f64` otherwise.
+#[cfg(feature = "decimal")]
+pub type Money = rust_decimal::Decimal;
+
+/// A monetary amount, `rust_decimal::Decimal` with the `decimal` feature enabled and `f64` otherwise.
+#[cfg(not(feature = "decimal"))]
+pub type Money = f64;
+
+string_enum! {
+    /// Locale of an asset.
+    pub enum Locale {
+        US = "us",
+        Global = "global",
     }
 }
 
@@ -25,17 +89,126 @@ impl fmt::Display for Order {
     }
 }
 
-#[derive(Debug)]
-pub enum Market {
-    Stocks,
-    Crypto,
-    FX,
-    OTC,
-    Indices,
+string_enum! {
+    /// Market type of an asset.
+    pub enum Market {
+        Stocks = "stocks",
+        Crypto = "crypto",
+        FX = "fx",
+        OTC = "otc",
+        Indices = "indices",
+    }
+}
+
+string_enum! {
+    /// Asset class of a ticker, exchange or condition.
+    pub enum AssetClass {
+        Stocks = "stocks",
+        Options = "options",
+        Indices = "indices",
+        FX = "fx",
+        Crypto = "crypto",
+    }
+}
+
+string_enum! {
+    /// Type of a ticker, see the [Ticker Types](https://polygon.io/docs/stocks/get_v3_reference_tickers_types) endpoint.
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum TickerType {
+        /// Common Stock
+        CS = "CS",
+        /// Preferred Stock
+        PFD = "PFD",
+        /// Warrant
+        WARRANT = "WARRANT",
+        /// Rights
+        RIGHT = "RIGHT",
+        /// Corporate Bond
+        BOND = "BOND",
+        /// Exchange Traded Fund
+        ETF = "ETF",
+        /// Exchange Traded Note
+        ETN = "ETN",
+        /// Exchange Traded Vehicle
+        ETV = "ETV",
+        /// Structured Product
+        SP = "SP",
+        /// American Depository Receipt Common
+        ADRC = "ADRC",
+        /// American Depository Receipt Preferred
+        ADRP = "ADRP",
+        /// American Depository Receipt Warrants
+        ADRW = "ADRW",
+        /// American Depository Receipt Rights
+        ADRR = "ADRR",
+        /// Fund
+        FUND = "FUND",
+        /// Basket
+        BASKET = "BASKET",
+        /// Unit
+        UNIT = "UNIT",
+        /// Liquidating Trust
+        LT = "LT",
+        /// Ordinary Shares
+        OS = "OS",
+        /// Global Depository Receipts
+        GDR = "GDR",
+        /// Other Security Type
+        OTHER = "OTHER",
+        /// New York Registry Shares
+        NYRS = "NYRS",
+        /// Agency Bond
+        AGEN = "AGEN",
+        /// Equity Linked Bond
+        EQLK = "EQLK",
+        /// Exchange Traded Product/Structure
+        ETS = "ETS",
+        /// Index
+        INDEX = "INDEX",
+    }
+}
+
+/// The SIP tape a trade or quote of a US stock was reported to.
+/// Tapes the api doesn't document deserialize to `Unknown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum Tape {
+    /// NYSE listed securities
+    A,
+    /// NYSE Arca, NYSE American and other regional listed securities
+    B,
+    /// Nasdaq listed securities
+    C,
+    Unknown(u8),
 }
 
-impl fmt::Display for Market {
+impl From<u8> for Tape {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Tape::A,
+            2 => Tape::B,
+            3 => Tape::C,
+            _ => Tape::Unknown(value),
+        }
+    }
+}
+
+impl From<Tape> for u8 {
+    fn from(tape: Tape) -> Self {
+        match tape {
+            Tape::A => 1,
+            Tape::B => 2,
+            Tape::C => 3,
+            Tape::Unknown(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Tape {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+        match self {
+            Tape::Unknown(value) => write!(f, "{}", value),
+            tape => write!(f, "{:?}", tape),
+        }
     }
 }
@@ -0,0 +1,196 @@
+use crate::common::string_enum;
+pub use crate::common::{AssetClass, Order};
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// /v3/reference/conditions
+/// List all conditions that Polygon.io uses.
+const CONDITIONS_PATH: &str = "/v3/reference/conditions";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ConditionsResponse {
+    /// The total number of results for this request.
+    #[serde(default = "i32::default")]
+    pub count: i32,
+    /// If present, this value can be used to fetch the next page of data.
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "Vec::default")]
+    pub results: Vec<Condition>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl Paginated for ConditionsResponse {
+    type Item = Condition;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<Condition> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Condition {
+    /// The id of the condition, as used in the `conditions` of trades and quotes.
+    /// Ids are only unique per asset class and data type.
+    pub id: u32,
+    /// The category of the condition, e.g. `sale_condition` or `quote_condition`.
+    #[serde(default = "String::default")]
+    pub r#type: String,
+    #[serde(default = "String::default")]
+    pub name: String,
+    /// A commonly used abbreviation of the name.
+    pub abbreviation: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub asset_class: AssetClass,
+    /// The data types the condition applies to.
+    #[serde(default = "Vec::default")]
+    pub data_types: Vec<DataType>,
+    /// The id of the exchange the condition is specific to, if any.
+    pub exchange: Option<u32>,
+    /// Whether the condition is no longer used.
+    #[serde(default = "bool::default")]
+    pub legacy: bool,
+    /// The code of the condition used by each SIP, e.g. `{"CTA": "B", "UTP": "W"}`.
+    #[serde(default = "HashMap::default")]
+    pub sip_mapping: HashMap<String, String>,
+    /// How a trade with the condition updates aggregates. Not set for quote conditions.
+    pub update_rules: Option<UpdateRules>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateRules {
+    /// The rules for the consolidated aggregates of all exchanges.
+    pub consolidated: UpdateRule,
+    /// The rules for the aggregates of the exchange the trade happened on.
+    pub market_center: UpdateRule,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UpdateRule {
+    /// Whether the trade price may set the high or the low.
+    pub updates_high_low: bool,
+    /// Whether the trade price may set the open or the close.
+    pub updates_open_close: bool,
+    /// Whether the trade size counts towards the volume.
+    pub updates_volume: bool,
+}
+
+string_enum! {
+    /// The kind of data a condition applies to.
+    pub enum DataType {
+        Trade = "trade",
+        /// Quotes of a single exchange
+        BBO = "bbo",
+        /// National best bid and offer quotes
+        NBBO = "nbbo",
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy)]
+pub enum ConditionsSort {
+    Asset_Class,
+    Id,
+    Type,
+    Name,
+    Data_Types,
+    Legacy,
+}
+
+impl fmt::Display for ConditionsSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct ConditionsRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl ConditionsRequest {
+    pub fn new() -> Self {
+        ConditionsRequest {
+            path: CONDITIONS_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Filter by asset class.
+    pub fn asset_class(mut self, asset_class: AssetClass) -> ConditionsRequest {
+        self.parameters.insert("asset_class".to_string(), asset_class.to_string());
+        self
+    }
+
+    /// Filter by the data type the conditions apply to.
+    pub fn data_type(mut self, data_type: DataType) -> ConditionsRequest {
+        self.parameters.insert("data_type".to_string(), data_type.to_string());
+        self
+    }
+
+    /// Filter by condition id.
+    pub fn id(mut self, id: u32) -> ConditionsRequest {
+        self.parameters.insert("id".to_string(), id.to_string());
+        self
+    }
+
+    /// Only return conditions which have a mapping for this SIP, e.g. `CTA`, `UTP` or `OPRA`.
+    pub fn sip(mut self, sip: impl Into<String>) -> ConditionsRequest {
+        self.parameters.insert("sip".to_string(), sip.into());
+        self
+    }
+
+    /// Order results based on the sort field.
+    pub fn order(mut self, order: Order) -> ConditionsRequest {
+        self.parameters.insert("order".to_string(), order.to_string());
+        self
+    }
+
+    /// Limit the number of results returned, default is 10 and max is 1000.
+    pub fn limit(mut self, limit: u32) -> ConditionsRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+
+    /// Sort field used for ordering.
+    pub fn sort(mut self, sort: ConditionsSort) -> ConditionsRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+}
+
+impl Default for ConditionsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for ConditionsRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 1000)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::common::Money;
+use crate::rest_client::RequestType;
+use crate::utils::{date_format, money_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::Date;
+
+/// /v1/open-close/{ticker}/{date}
+/// Get the open, close and afterhours prices of a stock symbol on a certain date.
+const DAILY_OPEN_CLOSE_PATH: &str = "/v1/open-close/{ticker}/{date}";
+
+#[derive(Debug)]
+pub struct DailyOpenCloseRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+    pub date: Option<Date>,
+}
+
+impl DailyOpenCloseRequest {
+    pub fn new() -> Self {
+        DailyOpenCloseRequest {
+            path: DAILY_OPEN_CLOSE_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+            date: None,
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> DailyOpenCloseRequest {
+        self.ticker = ticker.into();
+        self
+    }
+
+    /// The date of the requested open/close.
+    pub fn date(mut self, date: Date) -> DailyOpenCloseRequest {
+        self.date = Some(date);
+        self
+    }
+
+    /// Whether or not the results are adjusted for splits. By default, results are adjusted.
+    pub fn adjusted(mut self, adjusted: bool) -> DailyOpenCloseRequest {
+        self.parameters.insert("adjusted".to_string(), adjusted.to_string());
+        self
+    }
+}
+
+impl Default for DailyOpenCloseRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for DailyOpenCloseRequest {
+    fn get_url(&self) -> String {
+        format!(
+            "/v1/open-close/{}/{}",
+            self.ticker,
+            self.date.map(|date| date.to_string()).unwrap_or_default()
+        )
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)?;
+        match self.date {
+            Some(_) => Ok(()),
+            None => Err(ValidationError::MissingPathParameter("date")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DailyOpenCloseResponse {
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+    /// The exchange symbol that this item is traded under.
+    #[serde(default = "String::default")]
+    pub symbol: String,
+    /// The requested date.
+    #[serde(with = "date_format")]
+    pub from: Date,
+    /// The open price for the symbol in the given time period.
+    #[serde(with = "money_format")]
+    pub open: Money,
+    /// The highest price for the symbol in the given time period.
+    #[serde(with = "money_format")]
+    pub high: Money,
+    /// The lowest price for the symbol in the given time period.
+    #[serde(with = "money_format")]
+    pub low: Money,
+    /// The close price for the symbol in the given time period.
+    #[serde(with = "money_format")]
+    pub close: Money,
+    /// The trading volume of the symbol in the given time period.
+    pub volume: f64,
+    /// The open price of the ticker symbol in pre-market trading.
+    #[serde(rename = "preMarket", default, with = "money_format::option")]
+    pub pre_market: Option<Money>,
+    /// The close price of the ticker symbol in after hours trading.
+    #[serde(rename = "afterHours", default, with = "money_format::option")]
+    pub after_hours: Option<Money>,
+    /// Whether or not this aggregate is for an OTC ticker.
+    #[serde(default = "bool::default")]
+    pub otc: bool,
+}
@@ -1,41 +1,56 @@
-use crate::common::Order;
+use crate::common::{Money, Order};
+use crate::filter::RangeFilter;
+use crate::pagination::Paginated;
 use crate::rest_client::RequestType;
-use serde::Deserialize;
+use crate::utils::{date_format, money_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
+use time::Date;
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct DividendsResponse {
     #[serde(default = "String::default")]
-    next_url: String,
-    request_id: String,
-    results: Vec<Dividend>,
-    status: String,
+    pub next_url: String,
+    pub request_id: String,
+    pub results: Vec<Dividend>,
+    pub status: String,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+impl Paginated for DividendsResponse {
+    type Item = Dividend;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<Dividend> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Dividend {
-    #[serde(default = "f32::default")]
-    cash_amount: f32,
-    #[serde(default = "String::default")]
-    declaration_date: String,
-    dividend_type: DividendType,
-    #[serde(default = "String::default")]
-    ex_dividend_date: String,
+    #[serde(with = "money_format")]
+    pub cash_amount: Money,
+    #[serde(default, with = "date_format::option")]
+    pub declaration_date: Option<Date>,
+    pub dividend_type: DividendType,
+    #[serde(with = "date_format")]
+    pub ex_dividend_date: Date,
     #[serde(default = "i32::default")]
-    frequency: i32,
-    #[serde(default = "String::default")]
-    pay_date: String,
-    #[serde(default = "String::default")]
-    record_date: String,
+    pub frequency: i32,
+    #[serde(default, with = "date_format::option")]
+    pub pay_date: Option<Date>,
+    #[serde(default, with = "date_format::option")]
+    pub record_date: Option<Date>,
     #[serde(default = "String::default")]
-    ticker: String,
+    pub ticker: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum DividendType {
     CD,
     SC,
@@ -88,6 +103,12 @@ pub struct DividendRequest {
     pub parameters: HashMap<String, String>,
 }
 
+impl Default for DividendRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RequestType for DividendRequest {
     fn get_url(&self) -> String {
         "/v3/reference/dividends".to_string()
@@ -96,6 +117,16 @@ impl RequestType for DividendRequest {
     fn get_query(&self) -> Vec<(&String, &String)> {
         self.parameters.iter().collect()
     }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 1000)?;
+        validation::range::<String>(&self.parameters, "ticker")?;
+        validation::range::<Date>(&self.parameters, "ex_dividend_date")?;
+        validation::range::<Date>(&self.parameters, "record_date")?;
+        validation::range::<Date>(&self.parameters, "declaration_date")?;
+        validation::range::<Date>(&self.parameters, "pay_date")?;
+        validation::range::<Money>(&self.parameters, "cash_amount")
+    }
 }
 
 impl DividendRequest {
@@ -105,154 +136,39 @@ impl DividendRequest {
         }
     }
 
-    /// Specify a ticker symbol. Defaults to empty string which queries all tickers.
-    pub fn ticker(mut self, ticker: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ticker".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_gt(mut self, ticker: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ticker.gt".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_gte(mut self, ticker: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ticker.gte".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_lt(mut self, ticker: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ticker.lt".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_lte(mut self, ticker: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ticker.lte".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ex_dividend_date(mut self, ex_dividend_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ex_dividend_date".to_string(), ex_dividend_date.into());
-        self
-    }
-
-    pub fn ex_dividend_date_gt(mut self, ex_dividend_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ex_dividend_date.gt".to_string(), ex_dividend_date.into());
-        self
-    }
-
-    pub fn ex_dividend_date_gte(mut self, ex_dividend_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ex_dividend_date.gte".to_string(), ex_dividend_date.into());
-        self
-    }
-
-    pub fn ex_dividend_date_lt(mut self, ex_dividend_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ex_dividend_date.lt".to_string(), ex_dividend_date.into());
-        self
-    }
-
-    pub fn ex_dividend_date_lte(mut self, ex_dividend_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("ex_dividend_date.lte".to_string(), ex_dividend_date.into());
-        self
-    }
-
-    pub fn record_date(mut self, record_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("record_date".to_string(), record_date.into());
-        self
-    }
-
-    pub fn record_date_gt(mut self, record_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("record_date.gt".to_string(), record_date.into());
-        self
-    }
-
-    pub fn record_date_gte(mut self, record_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("record_date.gte".to_string(), record_date.into());
-        self
-    }
-
-    pub fn record_date_lt(mut self, record_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("record_date.lt".to_string(), record_date.into());
-        self
-    }
-
-    pub fn record_date_lte(mut self, record_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("record_date.lte".to_string(), record_date.into());
-        self
-    }
-
-    pub fn declaration_date(mut self, declaration_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("declaration_date".to_string(), declaration_date.into());
-        self
-    }
-
-    pub fn declaration_date_gt(mut self, declaration_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("declaration_date.gt".to_string(), declaration_date.into());
-        self
-    }
-
-    pub fn declaration_date_gte(mut self, declaration_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("declaration_date.gte".to_string(), declaration_date.into());
-        self
-    }
-
-    pub fn declaration_date_lt(mut self, declaration_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("declaration_date.lt".to_string(), declaration_date.into());
-        self
-    }
-
-    pub fn declaration_date_lte(mut self, declaration_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("declaration_date.lte".to_string(), declaration_date.into());
-        self
-    }
-
-    pub fn pay_date(mut self, pay_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("pay_date".to_string(), pay_date.into());
-        self
-    }
-
-    pub fn pay_date_gt(mut self, pay_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("pay_date.gt".to_string(), pay_date.into());
-        self
-    }
-
-    pub fn pay_date_gte(mut self, pay_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("pay_date.gte".to_string(), pay_date.into());
-        self
-    }
-
-    pub fn pay_date_lt(mut self, pay_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("pay_date.lt".to_string(), pay_date.into());
-        self
-    }
-
-    pub fn pay_date_lte(mut self, pay_date: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("pay_date.lte".to_string(), pay_date.into());
+    /// Filter by ticker symbol, either an exact symbol or a range. Defaults to empty string which queries all tickers.
+    pub fn ticker(mut self, ticker: impl Into<RangeFilter<String>>) -> DividendRequest {
+        ticker.into().apply("ticker", &mut self.parameters);
         self
     }
 
-    pub fn cash_amount(mut self, cash_amount: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("cash_amount".to_string(), cash_amount.into());
+    /// Filter by ex-dividend date, either an exact date or a range like `date!(2023 - 01 - 01)..`.
+    pub fn ex_dividend_date(mut self, ex_dividend_date: impl Into<RangeFilter<Date>>) -> DividendRequest {
+        ex_dividend_date.into().apply("ex_dividend_date", &mut self.parameters);
         self
     }
 
-    pub fn cash_amount_gt(mut self, cash_amount: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("cash_amount.gt".to_string(), cash_amount.into());
+    /// Filter by record date, either an exact date or a range.
+    pub fn record_date(mut self, record_date: impl Into<RangeFilter<Date>>) -> DividendRequest {
+        record_date.into().apply("record_date", &mut self.parameters);
         self
     }
 
-    pub fn cash_amount_gte(mut self, cash_amount: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("cash_amount.gte".to_string(), cash_amount.into());
+    /// Filter by declaration date, either an exact date or a range.
+    pub fn declaration_date(mut self, declaration_date: impl Into<RangeFilter<Date>>) -> DividendRequest {
+        declaration_date.into().apply("declaration_date", &mut self.parameters);
         self
     }
 
-    pub fn cash_amount_lt(mut self, cash_amount: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("cash_amount.lt".to_string(), cash_amount.into());
+    /// Filter by pay date, either an exact date or a range.
+    pub fn pay_date(mut self, pay_date: impl Into<RangeFilter<Date>>) -> DividendRequest {
+        pay_date.into().apply("pay_date", &mut self.parameters);
         self
     }
 
-    pub fn cash_amount_lte(mut self, cash_amount: impl Into<String>) -> DividendRequest {
-        self.parameters.insert("cash_amount.lte".to_string(), cash_amount.into());
+    /// Filter by cash amount, either an exact amount or a range like `RangeFilter::between(0.5, 2.0)`.
+    pub fn cash_amount(mut self, cash_amount: impl Into<RangeFilter<Money>>) -> DividendRequest {
+        cash_amount.into().apply("cash_amount", &mut self.parameters);
         self
     }
 
@@ -273,7 +189,6 @@ impl DividendRequest {
 
     /// Limit the number of results returned, default is 100 and max is 1000.
     pub fn limit(mut self, limit: u32) -> DividendRequest {
-        let limit = if limit > 1000 { 1000 } else { limit };
         self.parameters.insert("limit".to_string(), limit.to_string());
         self
     }
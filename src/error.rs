@@ -0,0 +1,194 @@
+use crate::validation::ValidationError;
+use std::fmt;
+use std::fmt::Formatter;
+use std::time::Duration;
+
+/// Maximum number of characters of a response body kept in a `PolygonError::Deserialize`.
+const BODY_SNIPPET_LEN: usize = 512;
+
+/// Errors returned by the Polygon.io client.
+#[derive(Debug)]
+pub enum PolygonError {
+    /// The client could not be configured, e.g. the auth key is missing.
+    Config(String),
+    /// The request is invalid and was not sent.
+    Validation(ValidationError),
+    /// The request was rejected because of the auth key (401 or 403).
+    Unauthorized { status: u16, message: String },
+    /// Too many requests were made (429). `retry_after` is taken from the `Retry-After` header when present.
+    RateLimited { retry_after: Option<Duration> },
+    /// The rate limit of the client was reached and it fails fast instead of waiting, so no request was sent.
+    /// `retry_after` is the time until the limit has capacity again.
+    LocalRateLimited { retry_after: Duration },
+    /// The requested resource, usually a ticker, does not exist (404).
+    NotFound { message: String },
+    /// The API answered with `"status": "ERROR"` or another non-success status in the body.
+    /// `http_status` is the status code of the response, 200 when the failure is only reported in the body.
+    Api {
+        http_status: u16,
+        status: String,
+        error: String,
+        message: String,
+        request_id: String,
+    },
+    /// Any other non-success HTTP status code.
+    Http { status: u16, message: String },
+    /// The response body could not be deserialized into the response type.
+    Deserialize { source: serde_json::Error, body: String },
+    /// The `next_url` of a paginated response could not be parsed.
+    InvalidNextUrl(String),
+    /// A cassette could not be recorded or no cassette was recorded for a replayed request.
+    Cassette(String),
+    /// The request timed out.
+    Timeout(reqwest::Error),
+    /// The request could not be sent or the response could not be read.
+    Transport(reqwest::Error),
+}
+
+impl PolygonError {
+    /// Returns the HTTP status code of the response which caused this error, if any.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            PolygonError::Unauthorized { status, .. } => Some(*status),
+            PolygonError::RateLimited { .. } => Some(429),
+            PolygonError::NotFound { .. } => Some(404),
+            PolygonError::Api { http_status, .. } => Some(*http_status),
+            PolygonError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns true for errors which may succeed when the request is sent again:
+    /// rate limiting, gateway errors (502, 503, 504) and connection resets.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            PolygonError::RateLimited { .. } | PolygonError::LocalRateLimited { .. } => true,
+            PolygonError::Api { http_status: status, .. } | PolygonError::Http { status, .. } => matches!(status, 502..=504),
+            PolygonError::Transport(e) => e.is_connect() || is_connection_reset(e),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn deserialize(source: serde_json::Error, body: &str) -> Self {
+        PolygonError::Deserialize {
+            source,
+            body: body.chars().take(BODY_SNIPPET_LEN).collect(),
+        }
+    }
+
+    pub(crate) fn from_response(status: u16, retry_after: Option<Duration>, body: &str) -> Self {
+        let api_error = serde_json::from_str::<ApiErrorBody>(body).unwrap_or_default();
+        let message = api_error.message();
+
+        match status {
+            401 | 403 => PolygonError::Unauthorized { status, message },
+            404 => PolygonError::NotFound { message },
+            429 => PolygonError::RateLimited { retry_after },
+            _ => api_error.into_error(status).unwrap_or(PolygonError::Http { status, message }),
+        }
+    }
+
+    /// Returns the error for a successful HTTP response whose body reports a failure, if it does.
+    pub(crate) fn from_body(body: &str) -> Option<Self> {
+        serde_json::from_str::<ApiErrorBody>(body).ok()?.into_error(200)
+    }
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonError::Config(message) => write!(f, "configuration error: {}", message),
+            PolygonError::Validation(e) => write!(f, "invalid request: {}", e),
+            PolygonError::Unauthorized { status, message } => write!(f, "unauthorized ({}): {}", status, message),
+            PolygonError::RateLimited {
+                retry_after: Some(retry_after),
+            } => {
+                write!(f, "rate limited, retry after {}s", retry_after.as_secs_f64())
+            }
+            PolygonError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            PolygonError::LocalRateLimited { retry_after } => write!(f, "client rate limit reached, retry after {}s", retry_after.as_secs_f64()),
+            PolygonError::NotFound { message } => write!(f, "not found: {}", message),
+            PolygonError::Api { status, error, message, .. } => write!(f, "api error ({}): {} {}", status, error, message),
+            PolygonError::Http { status, message } => write!(f, "http error ({}): {}", status, message),
+            PolygonError::Deserialize { source, body } => write!(f, "failed to deserialize response: {}, body: {}", source, body),
+            PolygonError::InvalidNextUrl(url) => write!(f, "invalid next_url: {}", url),
+            PolygonError::Cassette(message) => write!(f, "cassette error: {}", message),
+            PolygonError::Timeout(e) => write!(f, "request timed out: {}", e),
+            PolygonError::Transport(e) => write!(f, "transport error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PolygonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PolygonError::Validation(e) => Some(e),
+            PolygonError::Deserialize { source, .. } => Some(source),
+            PolygonError::Timeout(e) | PolygonError::Transport(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ValidationError> for PolygonError {
+    fn from(e: ValidationError) -> Self {
+        PolygonError::Validation(e)
+    }
+}
+
+impl From<reqwest::Error> for PolygonError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            PolygonError::Timeout(e)
+        } else {
+            PolygonError::Transport(e)
+        }
+    }
+}
+
+fn is_connection_reset(e: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(e);
+    while let Some(err) = source {
+        if let Some(io_error) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io_error.kind(),
+                std::io::ErrorKind::ConnectionReset | std::io::ErrorKind::ConnectionAborted | std::io::ErrorKind::BrokenPipe
+            );
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// The fields Polygon.io includes in an error response body.
+#[derive(Debug, Default, serde::Deserialize)]
+struct ApiErrorBody {
+    status: Option<String>,
+    error: Option<String>,
+    message: Option<String>,
+    request_id: Option<String>,
+}
+
+impl ApiErrorBody {
+    /// Returns the `Api` error if the body reports a failure.
+    fn into_error(self, http_status: u16) -> Option<PolygonError> {
+        match self.status.as_deref() {
+            Some("ERROR") | Some("NOT_AUTHORIZED") => Some(PolygonError::Api {
+                http_status,
+                status: self.status.unwrap_or_default(),
+                error: self.error.unwrap_or_default(),
+                message: self.message.unwrap_or_default(),
+                request_id: self.request_id.unwrap_or_default(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match (&self.error, &self.message) {
+            (Some(error), _) => error.clone(),
+            (None, Some(message)) => message.clone(),
+            (None, None) => String::new(),
+        }
+    }
+}
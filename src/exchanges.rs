@@ -0,0 +1,92 @@
+pub use crate::common::{AssetClass, Locale};
+use crate::rest_client::RequestType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// /v3/reference/exchanges
+/// List all exchanges that Polygon.io knows about.
+const EXCHANGES_PATH: &str = "/v3/reference/exchanges";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ExchangesResponse {
+    /// The total number of results for this request.
+    #[serde(default = "i32::default")]
+    pub count: i32,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "Vec::default")]
+    pub results: Vec<Exchange>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Exchange {
+    /// The id of the exchange, as used in the `exchange` of trades and quotes.
+    pub id: u32,
+    /// `exchange`, `TRF` (trade reporting facility), `SIP` (securities information processor) or `ORF` (options reporting facility).
+    #[serde(default = "String::default")]
+    pub r#type: String,
+    #[serde(default)]
+    pub asset_class: AssetClass,
+    #[serde(default)]
+    pub locale: Locale,
+    #[serde(default = "String::default")]
+    pub name: String,
+    /// A commonly used abbreviation of the name, e.g. `AMEX`.
+    pub acronym: Option<String>,
+    /// The ISO 10383 market identifier code (MIC) of the exchange, as used in the `primary_exchange` of a ticker.
+    pub mic: Option<String>,
+    /// The MIC of the entity operating the exchange.
+    #[serde(default = "String::default")]
+    pub operating_mic: String,
+    /// The id of the exchange as used by the SIP, e.g. `Q` for Nasdaq.
+    pub participant_id: Option<String>,
+    /// The website of the exchange.
+    pub url: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ExchangesRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl ExchangesRequest {
+    pub fn new() -> Self {
+        ExchangesRequest {
+            path: EXCHANGES_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Filter by asset class.
+    pub fn asset_class(mut self, asset_class: AssetClass) -> ExchangesRequest {
+        self.parameters.insert("asset_class".to_string(), asset_class.to_string());
+        self
+    }
+
+    /// Filter by locale.
+    pub fn locale(mut self, locale: Locale) -> ExchangesRequest {
+        self.parameters.insert("locale".to_string(), locale.to_string());
+        self
+    }
+}
+
+impl Default for ExchangesRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for ExchangesRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
@@ -0,0 +1,249 @@
+use crate::utils::timestamp_to_string;
+use std::collections::HashMap;
+use std::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
+/// A value which can be used in a query filter.
+pub trait FilterValue: Clone {
+    fn to_query_value(&self) -> String;
+
+    /// Parses a value rendered by `to_query_value`, used to validate requests.
+    fn from_query_value(value: &str) -> Option<Self>;
+}
+
+impl FilterValue for String {
+    fn to_query_value(&self) -> String {
+        self.clone()
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+impl FilterValue for Date {
+    fn to_query_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        Date::parse(value, format_description!("[year]-[month]-[day]")).ok()
+    }
+}
+
+impl FilterValue for OffsetDateTime {
+    fn to_query_value(&self) -> String {
+        timestamp_to_string(self)
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        OffsetDateTime::parse(value, &Rfc3339).ok()
+    }
+}
+
+impl FilterValue for f64 {
+    fn to_query_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        value.parse().ok().filter(|value: &f64| value.is_finite())
+    }
+}
+
+/// A timestamp sent as unix nanoseconds, the format of tick-level filters like `timestamp` of the trades endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NanoTimestamp(pub OffsetDateTime);
+
+impl From<OffsetDateTime> for NanoTimestamp {
+    fn from(timestamp: OffsetDateTime) -> Self {
+        NanoTimestamp(timestamp)
+    }
+}
+
+impl From<Date> for NanoTimestamp {
+    /// Midnight UTC of the date.
+    fn from(date: Date) -> Self {
+        NanoTimestamp(date.midnight().assume_utc())
+    }
+}
+
+impl FilterValue for NanoTimestamp {
+    fn to_query_value(&self) -> String {
+        self.0.unix_timestamp_nanos().to_string()
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        let nanos = value.parse().ok()?;
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).ok().map(NanoTimestamp)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl FilterValue for rust_decimal::Decimal {
+    fn to_query_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+/// Filter on a field, either an exact value or a range which is sent using the `.gt`, `.gte`, `.lt` and `.lte` suffixes.
+///
+/// Filters are usually created from a value or a range, e.g. `.ticker("AAPL")`, `.cash_amount(0.5..=2.0)`
+/// or `.ex_dividend_date(..=date)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeFilter<T> {
+    Equals(T),
+    Range { lower: Bound<T>, upper: Bound<T> },
+}
+
+impl<T: FilterValue> RangeFilter<T> {
+    pub fn equals(value: impl Into<T>) -> Self {
+        RangeFilter::Equals(value.into())
+    }
+
+    pub fn gt(value: impl Into<T>) -> Self {
+        RangeFilter::new(Bound::Excluded(value.into()), Bound::Unbounded)
+    }
+
+    pub fn gte(value: impl Into<T>) -> Self {
+        RangeFilter::new(Bound::Included(value.into()), Bound::Unbounded)
+    }
+
+    pub fn lt(value: impl Into<T>) -> Self {
+        RangeFilter::new(Bound::Unbounded, Bound::Excluded(value.into()))
+    }
+
+    pub fn lte(value: impl Into<T>) -> Self {
+        RangeFilter::new(Bound::Unbounded, Bound::Included(value.into()))
+    }
+
+    /// Values from `lower` to `upper`, both included.
+    pub fn between(lower: impl Into<T>, upper: impl Into<T>) -> Self {
+        RangeFilter::new(Bound::Included(lower.into()), Bound::Included(upper.into()))
+    }
+
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+        RangeFilter::Range { lower, upper }
+    }
+
+    /// Adds the query parameters of the filter for `field`, replacing any filter previously set on the field.
+    pub(crate) fn apply(&self, field: &str, parameters: &mut HashMap<String, String>) {
+        for suffix in ["", ".gt", ".gte", ".lt", ".lte"] {
+            parameters.remove(&format!("{}{}", field, suffix));
+        }
+
+        let mut insert = |suffix: &str, value: &T| {
+            parameters.insert(format!("{}{}", field, suffix), value.to_query_value());
+        };
+
+        match self {
+            RangeFilter::Equals(value) => insert("", value),
+            RangeFilter::Range { lower, upper } => {
+                match lower {
+                    Bound::Included(value) => insert(".gte", value),
+                    Bound::Excluded(value) => insert(".gt", value),
+                    Bound::Unbounded => {}
+                }
+                match upper {
+                    Bound::Included(value) => insert(".lte", value),
+                    Bound::Excluded(value) => insert(".lt", value),
+                    Bound::Unbounded => {}
+                }
+            }
+        }
+    }
+}
+
+impl<T> From<T> for RangeFilter<T> {
+    fn from(value: T) -> Self {
+        RangeFilter::Equals(value)
+    }
+}
+
+impl<T> From<Range<T>> for RangeFilter<T> {
+    fn from(range: Range<T>) -> Self {
+        RangeFilter::Range {
+            lower: Bound::Included(range.start),
+            upper: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for RangeFilter<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        RangeFilter::Range {
+            lower: Bound::Included(start),
+            upper: Bound::Included(end),
+        }
+    }
+}
+
+impl<T> From<RangeFrom<T>> for RangeFilter<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        RangeFilter::Range {
+            lower: Bound::Included(range.start),
+            upper: Bound::Unbounded,
+        }
+    }
+}
+
+impl<T> From<RangeTo<T>> for RangeFilter<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        RangeFilter::Range {
+            lower: Bound::Unbounded,
+            upper: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for RangeFilter<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        RangeFilter::Range {
+            lower: Bound::Unbounded,
+            upper: Bound::Included(range.end),
+        }
+    }
+}
+
+impl From<&str> for RangeFilter<String> {
+    fn from(value: &str) -> Self {
+        RangeFilter::Equals(value.to_string())
+    }
+}
+
+impl From<Range<&str>> for RangeFilter<String> {
+    fn from(range: Range<&str>) -> Self {
+        RangeFilter::from(range.start.to_string()..range.end.to_string())
+    }
+}
+
+impl From<RangeInclusive<&str>> for RangeFilter<String> {
+    fn from(range: RangeInclusive<&str>) -> Self {
+        RangeFilter::from(range.start().to_string()..=range.end().to_string())
+    }
+}
+
+impl From<RangeFrom<&str>> for RangeFilter<String> {
+    fn from(range: RangeFrom<&str>) -> Self {
+        RangeFilter::from(range.start.to_string()..)
+    }
+}
+
+impl From<RangeTo<&str>> for RangeFilter<String> {
+    fn from(range: RangeTo<&str>) -> Self {
+        RangeFilter::from(..range.end.to_string())
+    }
+}
+
+impl From<RangeToInclusive<&str>> for RangeFilter<String> {
+    fn from(range: RangeToInclusive<&str>) -> Self {
+        RangeFilter::from(..=range.end.to_string())
+    }
+}
@@ -0,0 +1,343 @@
+use crate::common::Order;
+use crate::filter::RangeFilter;
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::utils::date_format;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::Date;
+
+/// /vX/reference/financials
+/// Get historical financial data for a stock ticker, extracted from XBRL financial statements filed with the SEC.
+const FINANCIALS_PATH: &str = "/vX/reference/financials";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FinancialsResponse {
+    /// The total number of results for this request.
+    #[serde(default = "i32::default")]
+    pub count: i32,
+    /// If present, this value can be used to fetch the next page of data.
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "Vec::default")]
+    pub results: Vec<FinancialsResult>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl Paginated for FinancialsResponse {
+    type Item = FinancialsResult;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<FinancialsResult> {
+        self.results
+    }
+}
+
+/// The financial statements of one filing.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FinancialsResult {
+    /// The CIK number of the company.
+    #[serde(default = "String::default")]
+    pub cik: String,
+    #[serde(default = "String::default")]
+    pub company_name: String,
+    /// The tickers of the company.
+    #[serde(default = "Vec::default")]
+    pub tickers: Vec<String>,
+    /// The standard industrial classification code of the company.
+    #[serde(default = "String::default")]
+    pub sic: String,
+    /// The start date of the period the statements cover.
+    #[serde(default, with = "date_format::option")]
+    pub start_date: Option<Date>,
+    /// The end date of the period the statements cover.
+    #[serde(default, with = "date_format::option")]
+    pub end_date: Option<Date>,
+    /// The date the statements were filed with the SEC. Not set for TTM statements.
+    #[serde(default, with = "date_format::option")]
+    pub filing_date: Option<Date>,
+    /// The fiscal period of the report, `Q1`, `Q2`, `Q3`, `Q4`, `FY` or `TTM`.
+    #[serde(default = "String::default")]
+    pub fiscal_period: String,
+    #[serde(default = "String::default")]
+    pub fiscal_year: String,
+    /// `annual`, `quarterly` or `ttm`.
+    #[serde(default = "String::default")]
+    pub timeframe: String,
+    /// The url of the EDGAR filing the statements were extracted from.
+    #[serde(default = "String::default")]
+    pub source_filing_url: String,
+    /// The url of the XBRL instance of the filing.
+    #[serde(default = "String::default")]
+    pub source_filing_file_url: String,
+    /// The statements of the filing. Empty when the filing has none.
+    #[serde(default = "Financials::default")]
+    pub financials: Financials,
+}
+
+/// The statements of a filing. Which line items are present depends on the filing.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Financials {
+    #[serde(default = "BalanceSheet::default")]
+    pub balance_sheet: BalanceSheet,
+    #[serde(default = "IncomeStatement::default")]
+    pub income_statement: IncomeStatement,
+    #[serde(default = "CashFlowStatement::default")]
+    pub cash_flow_statement: CashFlowStatement,
+    #[serde(default = "ComprehensiveIncome::default")]
+    pub comprehensive_income: ComprehensiveIncome,
+}
+
+/// The assets, liabilities and equity at the end of the period.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct BalanceSheet {
+    /// Total assets.
+    pub assets: Option<LineItem>,
+    /// Assets expected to be used up within a year.
+    pub current_assets: Option<LineItem>,
+    /// Assets held for longer than a year.
+    pub noncurrent_assets: Option<LineItem>,
+    /// Total liabilities.
+    pub liabilities: Option<LineItem>,
+    /// Liabilities due within a year.
+    pub current_liabilities: Option<LineItem>,
+    /// Liabilities due after more than a year.
+    pub noncurrent_liabilities: Option<LineItem>,
+    /// Total equity, including noncontrolling interest.
+    pub equity: Option<LineItem>,
+    /// Equity of the shareholders of the company.
+    pub equity_attributable_to_parent: Option<LineItem>,
+    /// Total liabilities and equity.
+    pub liabilities_and_equity: Option<LineItem>,
+    /// The items of the filing without a field of their own, by their key.
+    #[serde(flatten)]
+    pub other: HashMap<String, LineItem>,
+}
+
+/// The revenues, expenses and earnings over the period.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct IncomeStatement {
+    /// Total revenues.
+    pub revenues: Option<LineItem>,
+    /// Cost of the goods and services sold.
+    pub cost_of_revenue: Option<LineItem>,
+    /// Revenues less the cost of revenue.
+    pub gross_profit: Option<LineItem>,
+    /// Total operating expenses.
+    pub operating_expenses: Option<LineItem>,
+    /// Gross profit less operating expenses.
+    pub operating_income_loss: Option<LineItem>,
+    /// Income from continuing operations before income taxes.
+    pub income_loss_from_continuing_operations_before_tax: Option<LineItem>,
+    /// Income tax expense, negative for a benefit.
+    pub income_tax_expense_benefit: Option<LineItem>,
+    /// Net income, negative for a loss.
+    pub net_income_loss: Option<LineItem>,
+    /// Net income of the shareholders of the company.
+    pub net_income_loss_attributable_to_parent: Option<LineItem>,
+    /// Basic earnings per share, in `USD / shares`.
+    pub basic_earnings_per_share: Option<LineItem>,
+    /// Diluted earnings per share, in `USD / shares`.
+    pub diluted_earnings_per_share: Option<LineItem>,
+    /// The items of the filing without a field of their own, by their key.
+    #[serde(flatten)]
+    pub other: HashMap<String, LineItem>,
+}
+
+/// The cash flows over the period.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct CashFlowStatement {
+    /// Net change in cash.
+    pub net_cash_flow: Option<LineItem>,
+    /// Net cash flow from operating activities.
+    pub net_cash_flow_from_operating_activities: Option<LineItem>,
+    /// Net cash flow from investing activities.
+    pub net_cash_flow_from_investing_activities: Option<LineItem>,
+    /// Net cash flow from financing activities.
+    pub net_cash_flow_from_financing_activities: Option<LineItem>,
+    /// The items of the filing without a field of their own, by their key.
+    #[serde(flatten)]
+    pub other: HashMap<String, LineItem>,
+}
+
+/// Net income and the gains and losses which bypass it over the period.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct ComprehensiveIncome {
+    /// Total comprehensive income, negative for a loss.
+    pub comprehensive_income_loss: Option<LineItem>,
+    /// Comprehensive income of the shareholders of the company.
+    pub comprehensive_income_loss_attributable_to_parent: Option<LineItem>,
+    /// Gains and losses which are not part of net income.
+    pub other_comprehensive_income_loss: Option<LineItem>,
+    /// The items of the filing without a field of their own, by their key.
+    #[serde(flatten)]
+    pub other: HashMap<String, LineItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LineItem {
+    /// Human readable name of the item, e.g. `Revenues`.
+    #[serde(default = "String::default")]
+    pub label: String,
+    /// Position of the item in the statement.
+    #[serde(default = "i32::default")]
+    pub order: i32,
+    /// Unit of the value, e.g. `USD`, `USD / shares` or `shares`.
+    #[serde(default = "String::default")]
+    pub unit: String,
+    pub value: f64,
+    /// How the value was obtained, only sent with `include_sources`.
+    pub source: Option<LineItemSource>,
+    /// Keys of the items the value was derived from, only sent with `include_sources`.
+    #[serde(default = "Vec::default")]
+    pub derived_from: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LineItemSource {
+    /// The formula the value was derived with, for values which are not reported directly.
+    pub formula: Option<String>,
+    /// The XPath of the value in the XBRL file, for reported values.
+    pub xpath: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinancialsTimeframe {
+    Annual,
+    Quarterly,
+    /// Trailing twelve months
+    TTM,
+}
+
+impl fmt::Display for FinancialsTimeframe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy)]
+pub enum FinancialsSort {
+    Filing_Date,
+    Period_Of_Report_Date,
+}
+
+impl fmt::Display for FinancialsSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct FinancialsRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl FinancialsRequest {
+    pub fn new() -> Self {
+        FinancialsRequest {
+            path: FINANCIALS_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Query by company ticker.
+    pub fn ticker(mut self, ticker: impl Into<String>) -> FinancialsRequest {
+        self.parameters.insert("ticker".to_string(), ticker.into());
+        self
+    }
+
+    /// Query by central index key (CIK) number.
+    pub fn cik(mut self, cik: impl Into<String>) -> FinancialsRequest {
+        self.parameters.insert("cik".to_string(), cik.into());
+        self
+    }
+
+    /// Query by company name, matching the exact name.
+    pub fn company_name(mut self, company_name: impl Into<String>) -> FinancialsRequest {
+        self.parameters.insert("company_name".to_string(), company_name.into());
+        self
+    }
+
+    /// Query by standard industrial classification (SIC) code.
+    pub fn sic(mut self, sic: impl Into<String>) -> FinancialsRequest {
+        self.parameters.insert("sic".to_string(), sic.into());
+        self
+    }
+
+    /// Filter by the date the statements were filed, either an exact date or a range.
+    pub fn filing_date(mut self, filing_date: impl Into<RangeFilter<Date>>) -> FinancialsRequest {
+        filing_date.into().apply("filing_date", &mut self.parameters);
+        self
+    }
+
+    /// Filter by the end date of the period the statements cover, either an exact date or a range.
+    pub fn period_of_report_date(mut self, period_of_report_date: impl Into<RangeFilter<Date>>) -> FinancialsRequest {
+        period_of_report_date.into().apply("period_of_report_date", &mut self.parameters);
+        self
+    }
+
+    /// Query by the timeframe of the statements. By default all timeframes are returned.
+    pub fn timeframe(mut self, timeframe: FinancialsTimeframe) -> FinancialsRequest {
+        self.parameters.insert("timeframe".to_string(), timeframe.to_string());
+        self
+    }
+
+    /// Whether or not to include the `source` and `derived_from` of each line item. Default is false.
+    pub fn include_sources(mut self, include_sources: bool) -> FinancialsRequest {
+        self.parameters.insert("include_sources".to_string(), include_sources.to_string());
+        self
+    }
+
+    /// Order results based on the sort field.
+    pub fn order(mut self, order: Order) -> FinancialsRequest {
+        self.parameters.insert("order".to_string(), order.to_string());
+        self
+    }
+
+    /// Limit the number of results returned, default is 10 and max is 100.
+    pub fn limit(mut self, limit: u32) -> FinancialsRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+
+    /// Sort field used for ordering.
+    pub fn sort(mut self, sort: FinancialsSort) -> FinancialsRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+}
+
+impl Default for FinancialsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for FinancialsRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 100)?;
+        validation::range::<Date>(&self.parameters, "filing_date")?;
+        validation::range::<Date>(&self.parameters, "period_of_report_date")
+    }
+}
@@ -0,0 +1,120 @@
+use crate::aggregates::TickerBar;
+use crate::common::{Locale, Market};
+use crate::rest_client::RequestType;
+use crate::validation::ValidationError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::Date;
+
+/// /v2/aggs/grouped/locale/{locale}/market/{market}/{date}
+/// Get the daily open, high, low, and close (OHLC) for the entire market.
+const GROUPED_DAILY_PATH: &str = "/v2/aggs/grouped/locale/{locale}/market/{market}/{date}";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GroupedDailyResponse {
+    /// Whether or not this response was adjusted for splits.
+    #[serde(default = "bool::default")]
+    pub adjusted: bool,
+    /// The number of aggregates (minute or day) used to generate the response.
+    #[serde(rename = "queryCount", default = "u64::default")]
+    pub query_count: u64,
+    /// The total number of results for this request.
+    #[serde(rename = "resultsCount", default = "u64::default")]
+    pub results_count: u64,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// One bar per ticker. Not sent when the market was closed on the date.
+    #[serde(default = "Vec::default")]
+    pub results: Vec<TickerBar>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+#[derive(Debug)]
+pub struct GroupedDailyRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub market: Market,
+    pub date: Option<Date>,
+}
+
+impl GroupedDailyRequest {
+    /// Bars of the stocks market, the date must be set.
+    pub fn new() -> Self {
+        GroupedDailyRequest {
+            path: GROUPED_DAILY_PATH,
+            parameters: HashMap::new(),
+            market: Market::Stocks,
+            date: None,
+        }
+    }
+
+    /// The market to get bars for, one of `Stocks`, `Crypto` or `FX`. Default is `Stocks`.
+    pub fn market(mut self, market: Market) -> GroupedDailyRequest {
+        self.market = market;
+        self
+    }
+
+    /// The trading day to get bars for.
+    pub fn date(mut self, date: Date) -> GroupedDailyRequest {
+        self.date = Some(date);
+        self
+    }
+
+    /// Whether or not the results are adjusted for splits. By default, results are adjusted.
+    pub fn adjusted(mut self, adjusted: bool) -> GroupedDailyRequest {
+        self.parameters.insert("adjusted".to_string(), adjusted.to_string());
+        self
+    }
+
+    /// Include OTC securities in the response. Default is false.
+    pub fn include_otc(mut self, include_otc: bool) -> GroupedDailyRequest {
+        self.parameters.insert("include_otc".to_string(), include_otc.to_string());
+        self
+    }
+
+    /// Stocks are listed under the `us` locale, crypto and fx under `global`.
+    fn locale(&self) -> Option<Locale> {
+        match self.market {
+            Market::Stocks => Some(Locale::US),
+            Market::Crypto | Market::FX => Some(Locale::Global),
+            _ => None,
+        }
+    }
+}
+
+impl Default for GroupedDailyRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for GroupedDailyRequest {
+    fn get_url(&self) -> String {
+        format!(
+            "/v2/aggs/grouped/locale/{}/market/{}/{}",
+            self.locale().unwrap_or_default(),
+            self.market,
+            self.date.map(|date| date.to_string()).unwrap_or_default()
+        )
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.locale().is_none() {
+            return Err(ValidationError::InvalidValue {
+                field: "market".to_string(),
+                value: self.market.to_string(),
+            });
+        }
+        match self.date {
+            Some(_) => Ok(()),
+            None => Err(ValidationError::MissingPathParameter("date")),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::common::{Money, Tape};
+use crate::rest_client::RequestType;
+use crate::utils::{millis_format, money_format, nanos_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// /v2/last/nbbo/{ticker}
+/// Get the most recent NBBO (Quote) tick for a given stock.
+const LAST_QUOTE_PATH: &str = "/v2/last/nbbo/{ticker}";
+
+/// /v1/last_quote/currencies/{from}/{to}
+/// Get the last quote tick for a forex currency pair.
+const FOREX_LAST_QUOTE_PATH: &str = "/v1/last_quote/currencies/{from}/{to}";
+
+#[derive(Debug)]
+pub struct LastQuoteRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+}
+
+impl LastQuoteRequest {
+    pub fn new() -> Self {
+        LastQuoteRequest {
+            path: LAST_QUOTE_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> LastQuoteRequest {
+        self.ticker = ticker.into();
+        self
+    }
+}
+
+impl Default for LastQuoteRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for LastQuoteRequest {
+    fn get_url(&self) -> String {
+        format!("/v2/last/nbbo/{}", self.ticker)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LastQuoteResponse {
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    pub results: LastQuote,
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LastQuote {
+    /// The exchange symbol that this item is traded under.
+    #[serde(rename = "T", default = "String::default")]
+    pub ticker: String,
+    /// The ask price. Not set when there is no ask.
+    #[serde(rename = "P", default, with = "money_format::option")]
+    pub ask_price: Option<Money>,
+    /// The ask size in round lots.
+    #[serde(rename = "S", default = "f64::default")]
+    pub ask_size: f64,
+    /// The id of the exchange of the ask, see the Exchanges endpoint for their names.
+    #[serde(rename = "X", default = "u32::default")]
+    pub ask_exchange: u32,
+    /// The quote conditions, see the Conditions endpoint for their meaning.
+    #[serde(rename = "c", default = "Vec::default")]
+    pub conditions: Vec<u32>,
+    /// The time at which the trade reporting facility received the quote.
+    #[serde(rename = "f", default, with = "nanos_format::option")]
+    pub trf_timestamp: Option<OffsetDateTime>,
+    /// The quote indicators, see the Conditions endpoint for their meaning.
+    #[serde(rename = "i", default = "Vec::default")]
+    pub indicators: Vec<u32>,
+    /// The bid price. Not set when there is no bid.
+    #[serde(rename = "p", default, with = "money_format::option")]
+    pub bid_price: Option<Money>,
+    /// The sequence number of the quote, increasing per ticker and trading day.
+    #[serde(rename = "q", default = "u64::default")]
+    pub sequence_number: u64,
+    /// The bid size in round lots.
+    #[serde(rename = "s", default = "f64::default")]
+    pub bid_size: f64,
+    /// The time at which the SIP received the quote from the exchange.
+    #[serde(rename = "t", with = "nanos_format")]
+    pub sip_timestamp: OffsetDateTime,
+    /// The id of the exchange of the bid, see the Exchanges endpoint for their names.
+    #[serde(rename = "x", default = "u32::default")]
+    pub bid_exchange: u32,
+    /// The time at which the quote was generated at the exchange.
+    #[serde(rename = "y", default, with = "nanos_format::option")]
+    pub participant_timestamp: Option<OffsetDateTime>,
+    /// The tape the quote was reported to.
+    #[serde(rename = "z", default)]
+    pub tape: Option<Tape>,
+}
+
+#[derive(Debug)]
+pub struct ForexLastQuoteRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub from: String,
+    pub to: String,
+}
+
+impl ForexLastQuoteRequest {
+    pub fn new() -> Self {
+        ForexLastQuoteRequest {
+            path: FOREX_LAST_QUOTE_PATH,
+            parameters: HashMap::new(),
+            from: String::new(),
+            to: String::new(),
+        }
+    }
+
+    /// The "from" currency of the pair, e.g. `AUD`.
+    pub fn from(mut self, from: impl Into<String>) -> ForexLastQuoteRequest {
+        self.from = from.into();
+        self
+    }
+
+    /// The "to" currency of the pair, e.g. `USD`.
+    pub fn to(mut self, to: impl Into<String>) -> ForexLastQuoteRequest {
+        self.to = to.into();
+        self
+    }
+}
+
+impl Default for ForexLastQuoteRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for ForexLastQuoteRequest {
+    fn get_url(&self) -> String {
+        format!("/v1/last_quote/currencies/{}/{}", self.from, self.to)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("from", &self.from)?;
+        validation::required("to", &self.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ForexLastQuoteResponse {
+    pub last: ForexQuote,
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "String::default")]
+    pub status: String,
+    /// The symbol pair that was evaluated from the request, e.g. `AUD/USD`.
+    #[serde(default = "String::default")]
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ForexQuote {
+    /// The ask price.
+    #[serde(with = "money_format")]
+    pub ask: Money,
+    /// The bid price.
+    #[serde(with = "money_format")]
+    pub bid: Money,
+    /// The id of the exchange of the quote, see the Exchanges endpoint for their names.
+    #[serde(default = "u32::default")]
+    pub exchange: u32,
+    /// The time of the quote.
+    #[serde(with = "millis_format")]
+    pub timestamp: OffsetDateTime,
+}
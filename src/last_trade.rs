@@ -0,0 +1,192 @@
+use crate::common::{Money, Tape};
+use crate::rest_client::RequestType;
+use crate::utils::{millis_format, money_format, nanos_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// /v2/last/trade/{ticker}
+/// Get the most recent trade for a ticker.
+const LAST_TRADE_PATH: &str = "/v2/last/trade/{ticker}";
+
+/// /v1/last/crypto/{from}/{to}
+/// Get the last trade tick for a cryptocurrency pair.
+const CRYPTO_LAST_TRADE_PATH: &str = "/v1/last/crypto/{from}/{to}";
+
+#[derive(Debug)]
+pub struct LastTradeRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+}
+
+impl LastTradeRequest {
+    pub fn new() -> Self {
+        LastTradeRequest {
+            path: LAST_TRADE_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> LastTradeRequest {
+        self.ticker = ticker.into();
+        self
+    }
+}
+
+impl Default for LastTradeRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for LastTradeRequest {
+    fn get_url(&self) -> String {
+        format!("/v2/last/trade/{}", self.ticker)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LastTradeResponse {
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    pub results: LastTrade,
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LastTrade {
+    /// The exchange symbol that this item is traded under.
+    #[serde(rename = "T", default = "String::default")]
+    pub ticker: String,
+    /// The trade conditions, see the Conditions endpoint for their meaning.
+    #[serde(rename = "c", default = "Vec::default")]
+    pub conditions: Vec<u32>,
+    /// The trade correction indicator.
+    #[serde(rename = "e", default)]
+    pub correction: Option<u32>,
+    /// The time at which the trade reporting facility received the trade.
+    #[serde(rename = "f", default, with = "nanos_format::option")]
+    pub trf_timestamp: Option<OffsetDateTime>,
+    /// The trade id, unique per ticker, exchange and trading day.
+    #[serde(rename = "i", default = "String::default")]
+    pub id: String,
+    /// The price of the trade.
+    #[serde(rename = "p", with = "money_format")]
+    pub price: Money,
+    /// The sequence number of the trade, increasing per ticker and trading day.
+    #[serde(rename = "q", default = "u64::default")]
+    pub sequence_number: u64,
+    /// The id of the trade reporting facility, for trades reported off exchange.
+    #[serde(rename = "r", default)]
+    pub trf_id: Option<u32>,
+    /// The number of shares traded.
+    #[serde(rename = "s")]
+    pub size: f64,
+    /// The time at which the SIP received the trade from the exchange.
+    #[serde(rename = "t", with = "nanos_format")]
+    pub sip_timestamp: OffsetDateTime,
+    /// The id of the exchange the trade happened on, see the Exchanges endpoint for their names.
+    #[serde(rename = "x", default = "u32::default")]
+    pub exchange: u32,
+    /// The time at which the trade was generated at the exchange.
+    #[serde(rename = "y", default, with = "nanos_format::option")]
+    pub participant_timestamp: Option<OffsetDateTime>,
+    /// The tape the trade was reported to.
+    #[serde(rename = "z", default)]
+    pub tape: Option<Tape>,
+}
+
+#[derive(Debug)]
+pub struct CryptoLastTradeRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub from: String,
+    pub to: String,
+}
+
+impl CryptoLastTradeRequest {
+    pub fn new() -> Self {
+        CryptoLastTradeRequest {
+            path: CRYPTO_LAST_TRADE_PATH,
+            parameters: HashMap::new(),
+            from: String::new(),
+            to: String::new(),
+        }
+    }
+
+    /// The "from" symbol of the pair, e.g. `BTC`.
+    pub fn from(mut self, from: impl Into<String>) -> CryptoLastTradeRequest {
+        self.from = from.into();
+        self
+    }
+
+    /// The "to" symbol of the pair, e.g. `USD`.
+    pub fn to(mut self, to: impl Into<String>) -> CryptoLastTradeRequest {
+        self.to = to.into();
+        self
+    }
+}
+
+impl Default for CryptoLastTradeRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for CryptoLastTradeRequest {
+    fn get_url(&self) -> String {
+        format!("/v1/last/crypto/{}/{}", self.from, self.to)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("from", &self.from)?;
+        validation::required("to", &self.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CryptoLastTradeResponse {
+    pub last: CryptoTrade,
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "String::default")]
+    pub status: String,
+    /// The symbol pair that was evaluated from the request, e.g. `BTC-USD`.
+    #[serde(default = "String::default")]
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CryptoTrade {
+    /// The trade conditions, see the Conditions endpoint for their meaning.
+    #[serde(default = "Vec::default")]
+    pub conditions: Vec<u32>,
+    /// The id of the exchange the trade happened on, see the Exchanges endpoint for their names.
+    #[serde(default = "u32::default")]
+    pub exchange: u32,
+    /// The price of the trade.
+    #[serde(with = "money_format")]
+    pub price: Money,
+    /// The size of the trade.
+    pub size: f64,
+    /// The time of the trade.
+    #[serde(with = "millis_format")]
+    pub timestamp: OffsetDateTime,
+}
@@ -1,9 +1,34 @@
+pub mod aggregates;
+pub mod cassette;
 pub mod common;
+pub mod conditions;
+pub mod daily_open_close;
 pub mod dividends;
+pub mod error;
+pub mod exchanges;
+pub mod filter;
+pub mod financials;
+pub mod grouped_daily;
+pub mod last_quote;
+pub mod last_trade;
+pub mod market_status;
+pub mod pagination;
 pub mod polygon_client;
+pub mod previous_close;
+pub mod quotes;
+pub mod rate_limiter;
+pub mod reference_tables;
 pub mod rest_client;
+pub mod retry;
+pub mod snapshots;
+pub mod splits;
 pub mod ticker_details;
 pub mod ticker_events;
 pub mod ticker_news;
+pub mod ticker_types;
 pub mod tickers;
+pub mod trades;
+pub mod transport;
+pub mod universal_snapshot;
 pub mod utils;
+pub mod validation;
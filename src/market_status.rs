@@ -0,0 +1,152 @@
+use crate::common::string_enum;
+use crate::rest_client::RequestType;
+use crate::utils::date_format;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::{Date, OffsetDateTime};
+
+/// /v1/marketstatus/now
+/// Get the current trading status of the exchanges and overall financial markets.
+const MARKET_STATUS_PATH: &str = "/v1/marketstatus/now";
+
+/// /v1/marketstatus/upcoming
+/// Get upcoming market holidays and their open/close times.
+const MARKET_HOLIDAYS_PATH: &str = "/v1/marketstatus/upcoming";
+
+#[derive(Debug)]
+pub struct MarketStatusRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl MarketStatusRequest {
+    pub fn new() -> Self {
+        MarketStatusRequest {
+            path: MARKET_STATUS_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+}
+
+impl Default for MarketStatusRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for MarketStatusRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketStatusResponse {
+    /// The status of the market as a whole.
+    pub market: MarketState,
+    /// Whether the market is in post-market hours.
+    #[serde(default = "bool::default")]
+    pub after_hours: bool,
+    /// Whether the market is in pre-market hours.
+    #[serde(default = "bool::default")]
+    pub early_hours: bool,
+    /// The status of the stock exchanges.
+    pub exchanges: ExchangesStatus,
+    /// The status of the crypto and forex markets.
+    pub currencies: CurrenciesStatus,
+    /// The status of index groups by their name, e.g. `s_and_p` or `dow_jones`.
+    #[serde(default = "HashMap::default")]
+    pub indices_groups: HashMap<String, MarketState>,
+    /// The current time of the server, in the eastern time zone.
+    #[serde(with = "time::serde::rfc3339")]
+    pub server_time: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ExchangesStatus {
+    pub nasdaq: MarketState,
+    pub nyse: MarketState,
+    pub otc: MarketState,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CurrenciesStatus {
+    pub crypto: MarketState,
+    pub fx: MarketState,
+}
+
+string_enum! {
+    /// Trading status of a market.
+    pub enum MarketState {
+        Open = "open",
+        Closed = "closed",
+        /// Pre-market or post-market hours
+        ExtendedHours = "extended-hours",
+    }
+}
+
+#[derive(Debug)]
+pub struct MarketHolidaysRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl MarketHolidaysRequest {
+    pub fn new() -> Self {
+        MarketHolidaysRequest {
+            path: MARKET_HOLIDAYS_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+}
+
+impl Default for MarketHolidaysRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for MarketHolidaysRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
+
+/// The upcoming holidays of all exchanges, ordered by date. A holiday observed by several exchanges is listed once per exchange.
+pub type MarketHolidaysResponse = Vec<MarketHoliday>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MarketHoliday {
+    #[serde(with = "date_format")]
+    pub date: Date,
+    /// The exchange observing the holiday, e.g. `NYSE` or `NASDAQ`.
+    #[serde(default = "String::default")]
+    pub exchange: String,
+    /// The name of the holiday, e.g. `Thanksgiving`.
+    #[serde(default = "String::default")]
+    pub name: String,
+    pub status: HolidayStatus,
+    /// The time the exchange opens on an early close day.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub open: Option<OffsetDateTime>,
+    /// The time the exchange closes on an early close day.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub close: Option<OffsetDateTime>,
+}
+
+string_enum! {
+    /// Whether an exchange is closed all day or closes early on a holiday.
+    pub enum HolidayStatus {
+        Closed = "closed",
+        EarlyClose = "early-close",
+    }
+}
@@ -0,0 +1,120 @@
+use crate::error::PolygonError;
+use crate::rest_client::{RequestType, RestClient};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// A response of a list endpoint which links to its next page through `next_url`.
+pub trait Paginated: DeserializeOwned {
+    type Item;
+
+    /// The url of the next page, if there is one.
+    fn next_url(&self) -> Option<&str>;
+
+    /// Consumes the response, returning the items of this page.
+    fn into_results(self) -> Vec<Self::Item>;
+}
+
+/// Limits how much of a paginated endpoint is fetched. By default all pages are fetched.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PaginationOptions {
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl PaginationOptions {
+    pub fn new() -> Self {
+        PaginationOptions::default()
+    }
+
+    /// Stop after this many pages have been fetched.
+    pub fn max_pages(mut self, max_pages: usize) -> PaginationOptions {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Stop after this many items have been returned.
+    pub fn max_items(mut self, max_items: usize) -> PaginationOptions {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+/// Request for the page a `next_url` points to.
+/// The host of the url is dropped so the page is fetched from the client's api url with its auth key.
+#[derive(Debug)]
+pub(crate) struct NextPageRequest {
+    path: String,
+    parameters: HashMap<String, String>,
+}
+
+impl NextPageRequest {
+    pub(crate) fn from_url(next_url: &str) -> Result<Self, PolygonError> {
+        let url = reqwest::Url::parse(next_url).map_err(|e| PolygonError::InvalidNextUrl(format!("{}: {}", next_url, e)))?;
+        let parameters = url
+            .query_pairs()
+            .filter(|(key, _)| key != "apiKey")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+
+        Ok(NextPageRequest {
+            path: url.path().to_string(),
+            parameters,
+        })
+    }
+}
+
+impl RequestType for NextPageRequest {
+    fn get_url(&self) -> String {
+        self.path.clone()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
+
+enum Page<'a, Request> {
+    First(&'a Request),
+    Next(NextPageRequest),
+    Done,
+}
+
+impl RestClient {
+    /// Sends the request and follows `next_url` until there are no more pages, yielding the items of every page.
+    pub(crate) fn paginate<'a, Request, ResponseType>(
+        &'a self,
+        request: &'a Request,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<ResponseType::Item, PolygonError>> + 'a
+    where
+        Request: RequestType,
+        ResponseType: Paginated + 'a,
+    {
+        let pages = stream::try_unfold((Page::First(request), 0usize), move |(page, page_count)| async move {
+            if options.max_pages.is_some_and(|max_pages| page_count >= max_pages) {
+                return Ok(None);
+            }
+
+            let response = match page {
+                Page::First(request) => self.send_request::<ResponseType>(request).await?,
+                Page::Next(request) => self.send_request::<ResponseType>(&request).await?,
+                Page::Done => return Ok(None),
+            };
+
+            let next_page = match response.next_url() {
+                Some(next_url) if !next_url.is_empty() => Page::Next(NextPageRequest::from_url(next_url)?),
+                _ => Page::Done,
+            };
+
+            Ok::<_, PolygonError>(Some((response.into_results(), (next_page, page_count + 1))))
+        });
+
+        let items = pages.map_ok(|results| stream::iter(results.into_iter().map(Ok))).try_flatten();
+
+        match options.max_items {
+            Some(max_items) => items.take(max_items).left_stream(),
+            None => items.right_stream(),
+        }
+    }
+}
@@ -1,12 +1,43 @@
-use crate::dividends::{DividendRequest, DividendsResponse};
+use crate::aggregates::{AggregatesRequest, AggregatesResponse, Bar};
+use crate::cassette::{RecordingTransport, ReplayTransport};
+use crate::common::AssetClass;
+use crate::conditions::{Condition, ConditionsRequest, ConditionsResponse};
+use crate::daily_open_close::{DailyOpenCloseRequest, DailyOpenCloseResponse};
+use crate::dividends::{Dividend, DividendRequest, DividendsResponse};
+use crate::error::PolygonError;
+use crate::exchanges::{ExchangesRequest, ExchangesResponse};
+use crate::financials::{FinancialsRequest, FinancialsResponse, FinancialsResult};
+use crate::grouped_daily::{GroupedDailyRequest, GroupedDailyResponse};
+use crate::last_quote::{ForexLastQuoteRequest, ForexLastQuoteResponse, LastQuoteRequest, LastQuoteResponse};
+use crate::last_trade::{CryptoLastTradeRequest, CryptoLastTradeResponse, LastTradeRequest, LastTradeResponse};
+use crate::market_status::{MarketHolidaysRequest, MarketHolidaysResponse, MarketStatusRequest, MarketStatusResponse};
+use crate::pagination::PaginationOptions;
+use crate::previous_close::{PreviousCloseRequest, PreviousCloseResponse};
+use crate::quotes::{Quote, QuotesRequest, QuotesResponse};
+use crate::rate_limiter::RateLimit;
+use crate::reference_tables::ReferenceTables;
 use crate::rest_client::RestClient;
+use crate::retry::RetryPolicy;
+use crate::snapshots::{
+    SnapshotGainersLosersRequest, SnapshotTickerRequest, SnapshotTickerResponse, SnapshotTickersRequest, SnapshotTickersResponse,
+};
+use crate::splits::{Split, SplitRequest, SplitsResponse};
 use crate::ticker_details::{TickerDetailsRequest, TickerDetailsResponse};
-use crate::ticker_news::{TickerNewsRequest, TickerNewsResponse};
-use crate::tickers::{TickersRequest, TickersResponse};
+use crate::ticker_events::{TickerEventsRequest, TickerEventsResponse};
+use crate::ticker_news::{TickerNewsRequest, TickerNewsResponse, TickerNewsResults};
+use crate::ticker_types::{TickerTypesRequest, TickerTypesResponse};
+use crate::tickers::{TickersRequest, TickersResponse, TickersResult};
+use crate::trades::{Trade, TradesRequest, TradesResponse};
+use crate::transport::{ReqwestTransport, Transport};
+use crate::universal_snapshot::{UniversalSnapshot, UniversalSnapshotRequest, UniversalSnapshotResponse};
+use futures::{Stream, TryStreamExt};
 use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 static POLYGON_IO_API_URL: &str = "https://api.polygon.io";
 
+#[derive(Clone)]
 pub struct PolygonClient {
     rest_client: RestClient,
 }
@@ -15,7 +46,15 @@ pub struct PolygonClientBuilder {
     auth_key: Option<String>,
     timeout: Option<core::time::Duration>,
     error: Option<String>,
-    // rate_limit: u32
+    rate_limit: Option<RateLimit>,
+    retry_policy: Option<RetryPolicy>,
+    transport: Option<Arc<dyn Transport>>,
+    cassettes: Option<Cassettes>,
+}
+
+enum Cassettes {
+    Record(PathBuf),
+    Replay(PathBuf),
 }
 
 impl PolygonClientBuilder {
@@ -24,6 +63,10 @@ impl PolygonClientBuilder {
             auth_key: None,
             timeout: Default::default(),
             error: None,
+            rate_limit: None,
+            retry_policy: None,
+            transport: None,
+            cassettes: None,
         }
     }
 
@@ -45,9 +88,43 @@ impl PolygonClientBuilder {
         self
     }
 
-    pub fn build(self) -> Result<PolygonClient, String> {
+    /// Limit the number of requests per period. The limit is shared by all clones of the client.
+    /// By default requests wait for capacity, see `RateLimit::fail_fast` to get an error instead.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> PolygonClientBuilder {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Retry rate limited requests, gateway errors and connection resets with exponential backoff.
+    /// Authentication errors are never retried. By default requests are sent only once.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> PolygonClientBuilder {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Send requests through `transport` instead of reqwest, e.g. a `MockTransport` to run without network access.
+    /// The auth key is optional and the timeout is ignored when a transport is set.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> PolygonClientBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Record every request and response to json cassettes in `dir`, see [replay](Self::replay).
+    pub fn record(mut self, dir: impl Into<PathBuf>) -> PolygonClientBuilder {
+        self.cassettes = Some(Cassettes::Record(dir.into()));
+        self
+    }
+
+    /// Answer requests from the cassettes in `dir` without network access.
+    /// Requests which were not recorded fail with `PolygonError::Cassette`. The auth key is optional.
+    pub fn replay(mut self, dir: impl Into<PathBuf>) -> PolygonClientBuilder {
+        self.cassettes = Some(Cassettes::Replay(dir.into()));
+        self
+    }
+
+    pub fn build(self) -> Result<PolygonClient, PolygonError> {
         if let Some(err) = self.error {
-            return Err(err);
+            return Err(PolygonError::Config(err));
         }
 
         let api_url = match env::var("POLYGON_API_URL") {
@@ -55,13 +132,32 @@ impl PolygonClientBuilder {
             _ => String::from(POLYGON_IO_API_URL),
         };
 
-        match self.auth_key {
-            None => Err("Auth key is not set".to_string()),
-            Some(auth_key) => {
-                let rest_client = RestClient::new(api_url, auth_key, self.timeout).unwrap();
-                Ok(PolygonClient { rest_client })
+        let transport: Option<Arc<dyn Transport>> = match (self.cassettes, self.transport) {
+            (Some(Cassettes::Replay(dir)), _) => Some(Arc::new(ReplayTransport::load(dir)?)),
+            (Some(Cassettes::Record(dir)), transport) => {
+                let inner = match transport {
+                    Some(transport) => transport,
+                    None => Arc::new(ReqwestTransport::new(self.timeout)?),
+                };
+                Some(Arc::new(RecordingTransport::new(inner, dir)?))
             }
+            (None, transport) => transport,
+        };
+
+        let mut rest_client = match (self.auth_key, transport) {
+            (auth_key, Some(transport)) => RestClient::with_transport(api_url, auth_key.unwrap_or_default(), transport),
+            (Some(auth_key), None) => RestClient::new(api_url, auth_key, self.timeout)?,
+            (None, None) => return Err(PolygonError::Config("Auth key is not set".to_string())),
+        };
+
+        if let Some(rate_limit) = self.rate_limit {
+            rest_client = rest_client.rate_limit(rate_limit);
         }
+        if let Some(retry_policy) = self.retry_policy {
+            rest_client = rest_client.retry_policy(retry_policy);
+        }
+
+        Ok(PolygonClient { rest_client })
     }
 }
 
@@ -75,28 +171,267 @@ impl PolygonClient {
     /// Query all ticker symbols which are supported by Polygon.io.
     /// This API currently includes Stocks/Equities, Indices, Forex, and Crypto.
     /// [/v3/reference/tickers](https://polygon.io/docs/stocks/get_v3_reference_tickers)
-    pub async fn get_tickers(&self, request: &TickersRequest) -> Result<TickersResponse, reqwest::Error> {
+    pub async fn get_tickers(&self, request: &TickersRequest) -> Result<TickersResponse, PolygonError> {
         self.rest_client.send_request::<TickersResponse>(request).await
     }
 
+    /// Same as [get_tickers](Self::get_tickers), but follows `next_url` and yields the tickers of every page.
+    pub fn tickers_stream<'a>(
+        &'a self,
+        request: &'a TickersRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<TickersResult, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, TickersResponse>(request, options)
+    }
+
     /// Get a single ticker supported by Polygon.io.
     /// This response will have detailed information about the ticker and the company behind it.
     /// [/v3/reference/tickers/{ticker}](https://polygon.io/docs/stocks/get_v3_reference_tickers__ticker)
-    // pub async fn get_tickers_details(&self, request: &TickerDetailsRequest) -> Result<TickerDetailsResponse, reqwest::Error> {
-    pub async fn get_tickers_details(&self, request: &TickerDetailsRequest) -> Result<TickerDetailsResponse, reqwest::Error> {
+    pub async fn get_tickers_details(&self, request: &TickerDetailsRequest) -> Result<TickerDetailsResponse, PolygonError> {
         self.rest_client.send_request::<TickerDetailsResponse>(request).await
     }
 
+    /// Get a timeline of events for the entity associated with the given ticker, CUSIP, or Composite FIGI,
+    /// e.g. the ticker changes of a company.
+    /// [/vX/reference/tickers/{id}/events](https://polygon.io/docs/stocks/get_vx_reference_tickers__id__events)
+    pub async fn get_ticker_events(&self, request: &TickerEventsRequest) -> Result<TickerEventsResponse, PolygonError> {
+        self.rest_client.send_request::<TickerEventsResponse>(request).await
+    }
+
     /// Get the most recent news articles relating to a stock ticker symbol,
     /// including a summary of the article and a link to the original source.
     /// [/v2/reference/news](https://polygon.io/docs/stocks/get_v2_reference_news)
-    pub async fn get_ticker_news(&self, request: &TickerNewsRequest) -> Result<TickerNewsResponse, reqwest::Error> {
+    pub async fn get_ticker_news(&self, request: &TickerNewsRequest) -> Result<TickerNewsResponse, PolygonError> {
         self.rest_client.send_request::<TickerNewsResponse>(request).await
     }
 
+    /// Same as [get_ticker_news](Self::get_ticker_news), but follows `next_url` and yields the articles of every page.
+    pub fn ticker_news_stream<'a>(
+        &'a self,
+        request: &'a TickerNewsRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<TickerNewsResults, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, TickerNewsResponse>(request, options)
+    }
+
     // Get a list of historical cash dividends, including the ticker symbol, declaration date, ex-dividend date, record date, pay date, frequency, and amount.
     /// [/v3/reference/dividends](https://polygon.io/docs/stocks/get_v3_reference_dividends)
-    pub async fn get_dividends(&self, request: &DividendRequest) -> Result<DividendsResponse, reqwest::Error> {
+    pub async fn get_dividends(&self, request: &DividendRequest) -> Result<DividendsResponse, PolygonError> {
         self.rest_client.send_request::<DividendsResponse>(request).await
     }
+
+    /// Same as [get_dividends](Self::get_dividends), but follows `next_url` and yields the dividends of every page.
+    pub fn dividends_stream<'a>(
+        &'a self,
+        request: &'a DividendRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Dividend, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, DividendsResponse>(request, options)
+    }
+
+    /// Get a list of historical stock splits, including the ticker symbol, the execution date, and the factors of the split ratio.
+    /// [/v3/reference/splits](https://polygon.io/docs/stocks/get_v3_reference_splits)
+    pub async fn get_splits(&self, request: &SplitRequest) -> Result<SplitsResponse, PolygonError> {
+        self.rest_client.send_request::<SplitsResponse>(request).await
+    }
+
+    /// Same as [get_splits](Self::get_splits), but follows `next_url` and yields the splits of every page.
+    pub fn splits_stream<'a>(
+        &'a self,
+        request: &'a SplitRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Split, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, SplitsResponse>(request, options)
+    }
+
+    /// Get historical financial data for a stock ticker, extracted from XBRL financial statements filed with the SEC.
+    /// [/vX/reference/financials](https://polygon.io/docs/stocks/get_vx_reference_financials)
+    pub async fn get_financials(&self, request: &FinancialsRequest) -> Result<FinancialsResponse, PolygonError> {
+        self.rest_client.send_request::<FinancialsResponse>(request).await
+    }
+
+    /// Same as [get_financials](Self::get_financials), but follows `next_url` and yields the filings of every page.
+    pub fn financials_stream<'a>(
+        &'a self,
+        request: &'a FinancialsRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<FinancialsResult, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, FinancialsResponse>(request, options)
+    }
+
+    /// List all ticker types that Polygon.io has.
+    /// [/v3/reference/tickers/types](https://polygon.io/docs/stocks/get_v3_reference_tickers_types)
+    pub async fn get_ticker_types(&self, request: &TickerTypesRequest) -> Result<TickerTypesResponse, PolygonError> {
+        self.rest_client.send_request::<TickerTypesResponse>(request).await
+    }
+
+    /// List all exchanges that Polygon.io knows about.
+    /// [/v3/reference/exchanges](https://polygon.io/docs/stocks/get_v3_reference_exchanges)
+    pub async fn get_exchanges(&self, request: &ExchangesRequest) -> Result<ExchangesResponse, PolygonError> {
+        self.rest_client.send_request::<ExchangesResponse>(request).await
+    }
+
+    /// List all conditions that Polygon.io uses.
+    /// [/v3/reference/conditions](https://polygon.io/docs/stocks/get_v3_reference_conditions)
+    pub async fn get_conditions(&self, request: &ConditionsRequest) -> Result<ConditionsResponse, PolygonError> {
+        self.rest_client.send_request::<ConditionsResponse>(request).await
+    }
+
+    /// Same as [get_conditions](Self::get_conditions), but follows `next_url` and yields the conditions of every page.
+    pub fn conditions_stream<'a>(
+        &'a self,
+        request: &'a ConditionsRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Condition, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, ConditionsResponse>(request, options)
+    }
+
+    /// Load the ticker types, exchanges and conditions of an asset class into lookup tables.
+    pub async fn load_reference_tables(&self, asset_class: AssetClass) -> Result<ReferenceTables, PolygonError> {
+        let ticker_types = self.get_ticker_types(&TickerTypesRequest::new().asset_class(asset_class.clone())).await?;
+        let exchanges = self.get_exchanges(&ExchangesRequest::new().asset_class(asset_class.clone())).await?;
+        let conditions_request = ConditionsRequest::new().asset_class(asset_class).limit(1000);
+        let conditions: Vec<Condition> = self
+            .conditions_stream(&conditions_request, PaginationOptions::new())
+            .try_collect()
+            .await?;
+
+        Ok(ReferenceTables::new(ticker_types.results, exchanges.results, conditions))
+    }
+
+    /// Get aggregate bars for a stock over a given date range in custom time window sizes.
+    /// For example, if timespan = 'minute' and multiplier = '5' then 5-minute bars will be returned.
+    /// [/v2/aggs/ticker/{ticker}/range/{multiplier}/{timespan}/{from}/{to}](https://polygon.io/docs/stocks/get_v2_aggs_ticker__stocksticker__range__multiplier___timespan___from___to)
+    pub async fn get_aggregates(&self, request: &AggregatesRequest) -> Result<AggregatesResponse, PolygonError> {
+        self.rest_client.send_request::<AggregatesResponse>(request).await
+    }
+
+    /// Same as [get_aggregates](Self::get_aggregates), but follows `next_url` and yields the bars of every page.
+    pub fn aggregates_stream<'a>(
+        &'a self,
+        request: &'a AggregatesRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Bar, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, AggregatesResponse>(request, options)
+    }
+
+    /// Get the daily open, high, low, and close (OHLC) for the entire stocks, crypto or forex market.
+    /// [/v2/aggs/grouped/locale/{locale}/market/{market}/{date}](https://polygon.io/docs/stocks/get_v2_aggs_grouped_locale_us_market_stocks__date)
+    pub async fn get_grouped_daily(&self, request: &GroupedDailyRequest) -> Result<GroupedDailyResponse, PolygonError> {
+        self.rest_client.send_request::<GroupedDailyResponse>(request).await
+    }
+
+    /// Get the open, close and afterhours prices of a stock symbol on a certain date.
+    /// [/v1/open-close/{ticker}/{date}](https://polygon.io/docs/stocks/get_v1_open-close__stocksticker___date)
+    pub async fn get_daily_open_close(&self, request: &DailyOpenCloseRequest) -> Result<DailyOpenCloseResponse, PolygonError> {
+        self.rest_client.send_request::<DailyOpenCloseResponse>(request).await
+    }
+
+    /// Get the previous day's open, high, low, and close (OHLC) for the specified stock ticker.
+    /// [/v2/aggs/ticker/{ticker}/prev](https://polygon.io/docs/stocks/get_v2_aggs_ticker__stocksticker__prev)
+    pub async fn get_previous_close(&self, request: &PreviousCloseRequest) -> Result<PreviousCloseResponse, PolygonError> {
+        self.rest_client.send_request::<PreviousCloseResponse>(request).await
+    }
+
+    /// Get trades for a ticker symbol in a given time range.
+    /// [/v3/trades/{ticker}](https://polygon.io/docs/stocks/get_v3_trades__stockticker)
+    pub async fn get_trades(&self, request: &TradesRequest) -> Result<TradesResponse, PolygonError> {
+        self.rest_client.send_request::<TradesResponse>(request).await
+    }
+
+    /// Same as [get_trades](Self::get_trades), but follows `next_url` and yields the trades of every page.
+    /// Pages are fetched as the stream is polled, so only one page is held in memory at a time.
+    pub fn trades_stream<'a>(
+        &'a self,
+        request: &'a TradesRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Trade, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, TradesResponse>(request, options)
+    }
+
+    /// Get NBBO quotes for a ticker symbol in a given time range.
+    /// [/v3/quotes/{ticker}](https://polygon.io/docs/stocks/get_v3_quotes__stockticker)
+    pub async fn get_quotes(&self, request: &QuotesRequest) -> Result<QuotesResponse, PolygonError> {
+        self.rest_client.send_request::<QuotesResponse>(request).await
+    }
+
+    /// Same as [get_quotes](Self::get_quotes), but follows `next_url` and yields the quotes of every page.
+    /// Pages are fetched as the stream is polled, so only one page is held in memory at a time.
+    pub fn quotes_stream<'a>(
+        &'a self,
+        request: &'a QuotesRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Quote, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, QuotesResponse>(request, options)
+    }
+
+    /// Get the most recent trade for a ticker.
+    /// [/v2/last/trade/{ticker}](https://polygon.io/docs/stocks/get_v2_last_trade__stocksticker)
+    pub async fn get_last_trade(&self, request: &LastTradeRequest) -> Result<LastTradeResponse, PolygonError> {
+        self.rest_client.send_request::<LastTradeResponse>(request).await
+    }
+
+    /// Get the most recent NBBO (Quote) tick for a given stock.
+    /// [/v2/last/nbbo/{ticker}](https://polygon.io/docs/stocks/get_v2_last_nbbo__stocksticker)
+    pub async fn get_last_quote(&self, request: &LastQuoteRequest) -> Result<LastQuoteResponse, PolygonError> {
+        self.rest_client.send_request::<LastQuoteResponse>(request).await
+    }
+
+    /// Get the last trade tick for a cryptocurrency pair.
+    /// [/v1/last/crypto/{from}/{to}](https://polygon.io/docs/crypto/get_v1_last_crypto__from___to)
+    pub async fn get_crypto_last_trade(&self, request: &CryptoLastTradeRequest) -> Result<CryptoLastTradeResponse, PolygonError> {
+        self.rest_client.send_request::<CryptoLastTradeResponse>(request).await
+    }
+
+    /// Get the last quote tick for a forex currency pair.
+    /// [/v1/last_quote/currencies/{from}/{to}](https://polygon.io/docs/forex/get_v1_last_quote_currencies__from___to)
+    pub async fn get_forex_last_quote(&self, request: &ForexLastQuoteRequest) -> Result<ForexLastQuoteResponse, PolygonError> {
+        self.rest_client.send_request::<ForexLastQuoteResponse>(request).await
+    }
+
+    /// Get the most up-to-date market data for all traded stock symbols, or the ones set with `tickers`.
+    /// [/v2/snapshot/locale/us/markets/stocks/tickers](https://polygon.io/docs/stocks/get_v2_snapshot_locale_us_markets_stocks_tickers)
+    pub async fn get_snapshot_all_tickers(&self, request: &SnapshotTickersRequest) -> Result<SnapshotTickersResponse, PolygonError> {
+        self.rest_client.send_request::<SnapshotTickersResponse>(request).await
+    }
+
+    /// Get the most up-to-date market data for a single traded stock ticker.
+    /// [/v2/snapshot/locale/us/markets/stocks/tickers/{ticker}](https://polygon.io/docs/stocks/get_v2_snapshot_locale_us_markets_stocks_tickers__stocksticker)
+    pub async fn get_snapshot_ticker(&self, request: &SnapshotTickerRequest) -> Result<SnapshotTickerResponse, PolygonError> {
+        self.rest_client.send_request::<SnapshotTickerResponse>(request).await
+    }
+
+    /// Get the most up-to-date market data for the current top 20 gainers or losers of the day in the stocks market.
+    /// [/v2/snapshot/locale/us/markets/stocks/{direction}](https://polygon.io/docs/stocks/get_v2_snapshot_locale_us_markets_stocks__direction)
+    pub async fn get_snapshot_gainers_losers(&self, request: &SnapshotGainersLosersRequest) -> Result<SnapshotTickersResponse, PolygonError> {
+        self.rest_client.send_request::<SnapshotTickersResponse>(request).await
+    }
+
+    /// Get snapshots for assets of all types, e.g. a list of stocks, options, indices, fx and crypto tickers in one call.
+    /// Tickers which could not be found are returned as `UniversalSnapshot::Error`.
+    /// [/v3/snapshot](https://polygon.io/docs/stocks/get_v3_snapshot)
+    pub async fn get_universal_snapshot(&self, request: &UniversalSnapshotRequest) -> Result<UniversalSnapshotResponse, PolygonError> {
+        self.rest_client.send_request::<UniversalSnapshotResponse>(request).await
+    }
+
+    /// Same as [get_universal_snapshot](Self::get_universal_snapshot), but follows `next_url` and yields the snapshots of every page.
+    pub fn universal_snapshot_stream<'a>(
+        &'a self,
+        request: &'a UniversalSnapshotRequest,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<UniversalSnapshot, PolygonError>> + 'a {
+        self.rest_client.paginate::<_, UniversalSnapshotResponse>(request, options)
+    }
+
+    /// Get the current trading status of the exchanges and overall financial markets.
+    /// [/v1/marketstatus/now](https://polygon.io/docs/stocks/get_v1_marketstatus_now)
+    pub async fn get_market_status(&self, request: &MarketStatusRequest) -> Result<MarketStatusResponse, PolygonError> {
+        self.rest_client.send_request::<MarketStatusResponse>(request).await
+    }
+
+    /// Get upcoming market holidays and their open/close times.
+    /// [/v1/marketstatus/upcoming](https://polygon.io/docs/stocks/get_v1_marketstatus_upcoming)
+    pub async fn get_market_holidays(&self, request: &MarketHolidaysRequest) -> Result<MarketHolidaysResponse, PolygonError> {
+        self.rest_client.send_request::<MarketHolidaysResponse>(request).await
+    }
 }
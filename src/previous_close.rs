@@ -0,0 +1,90 @@
+use crate::aggregates::TickerBar;
+use crate::rest_client::RequestType;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// /v2/aggs/ticker/{ticker}/prev
+/// Get the previous day's open, high, low, and close (OHLC) for the specified stock ticker.
+const PREVIOUS_CLOSE_PATH: &str = "/v2/aggs/ticker/{ticker}/prev";
+
+#[derive(Debug)]
+pub struct PreviousCloseRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+}
+
+impl PreviousCloseRequest {
+    pub fn new() -> Self {
+        PreviousCloseRequest {
+            path: PREVIOUS_CLOSE_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> PreviousCloseRequest {
+        self.ticker = ticker.into();
+        self
+    }
+
+    /// Whether or not the results are adjusted for splits. By default, results are adjusted.
+    pub fn adjusted(mut self, adjusted: bool) -> PreviousCloseRequest {
+        self.parameters.insert("adjusted".to_string(), adjusted.to_string());
+        self
+    }
+}
+
+impl Default for PreviousCloseRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for PreviousCloseRequest {
+    fn get_url(&self) -> String {
+        format!("/v2/aggs/ticker/{}/prev", self.ticker)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PreviousCloseResponse {
+    /// The exchange symbol that this item is traded under.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// Whether or not this response was adjusted for splits.
+    #[serde(default = "bool::default")]
+    pub adjusted: bool,
+    /// The number of aggregates (minute or day) used to generate the response.
+    #[serde(rename = "queryCount", default = "u64::default")]
+    pub query_count: u64,
+    /// The total number of results for this request.
+    #[serde(rename = "resultsCount", default = "u64::default")]
+    pub results_count: u64,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// The bar of the previous session. Empty when the ticker has not traded yet.
+    #[serde(default = "Vec::default")]
+    pub results: Vec<TickerBar>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl PreviousCloseResponse {
+    /// The bar of the previous session, if there is one.
+    pub fn bar(&self) -> Option<&TickerBar> {
+        self.results.first()
+    }
+}
@@ -0,0 +1,166 @@
+use crate::common::{Money, Order, Tape};
+use crate::filter::{NanoTimestamp, RangeFilter};
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::utils::{money_format, nanos_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::OffsetDateTime;
+
+/// /v3/quotes/{ticker}
+/// Get NBBO quotes for a ticker symbol in a given time range.
+const QUOTES_PATH: &str = "/v3/quotes/{ticker}";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct QuotesResponse {
+    /// If present, this value can be used to fetch the next page of data.
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// The quotes of this page.
+    #[serde(default = "Vec::default")]
+    pub results: Vec<Quote>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl Paginated for QuotesResponse {
+    type Item = Quote;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<Quote> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Quote {
+    /// The id of the exchange of the ask, see the Exchanges endpoint for their names.
+    #[serde(default = "u32::default")]
+    pub ask_exchange: u32,
+    /// The ask price. Not set when there is no ask.
+    #[serde(default, with = "money_format::option")]
+    pub ask_price: Option<Money>,
+    /// The ask size in round lots. Fractional for crypto.
+    #[serde(default = "f64::default")]
+    pub ask_size: f64,
+    /// The id of the exchange of the bid, see the Exchanges endpoint for their names.
+    #[serde(default = "u32::default")]
+    pub bid_exchange: u32,
+    /// The bid price. Not set when there is no bid.
+    #[serde(default, with = "money_format::option")]
+    pub bid_price: Option<Money>,
+    /// The bid size in round lots. Fractional for crypto.
+    #[serde(default = "f64::default")]
+    pub bid_size: f64,
+    /// The quote conditions, see the Conditions endpoint for their meaning.
+    #[serde(default = "Vec::default")]
+    pub conditions: Vec<u32>,
+    /// The quote indicators, see the Conditions endpoint for their meaning.
+    #[serde(default = "Vec::default")]
+    pub indicators: Vec<u32>,
+    /// The time at which the quote was generated at the exchange.
+    #[serde(default, with = "nanos_format::option")]
+    pub participant_timestamp: Option<OffsetDateTime>,
+    /// The sequence number of the quote, increasing per ticker and trading day.
+    #[serde(default = "u64::default")]
+    pub sequence_number: u64,
+    /// The time at which the SIP received the quote from the exchange.
+    #[serde(with = "nanos_format")]
+    pub sip_timestamp: OffsetDateTime,
+    /// The tape the quote was reported to. Not set for crypto and fx.
+    pub tape: Option<Tape>,
+    /// The time at which the trade reporting facility received the quote.
+    #[serde(default, with = "nanos_format::option")]
+    pub trf_timestamp: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QuotesSort {
+    Timestamp,
+}
+
+impl fmt::Display for QuotesSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct QuotesRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+}
+
+impl QuotesRequest {
+    pub fn new() -> Self {
+        QuotesRequest {
+            path: QUOTES_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> QuotesRequest {
+        self.ticker = ticker.into();
+        self
+    }
+
+    /// Filter by SIP timestamp, either an exact timestamp or a range like
+    /// `NanoTimestamp::from(date!(2023 - 01 - 09))..NanoTimestamp::from(date!(2023 - 01 - 10))`.
+    pub fn timestamp(mut self, timestamp: impl Into<RangeFilter<NanoTimestamp>>) -> QuotesRequest {
+        timestamp.into().apply("timestamp", &mut self.parameters);
+        self
+    }
+
+    /// Order results based on the sort field.
+    pub fn order(mut self, order: Order) -> QuotesRequest {
+        self.parameters.insert("order".to_string(), order.to_string());
+        self
+    }
+
+    /// Limit the number of results returned, default is 1000 and max is 50000.
+    pub fn limit(mut self, limit: u32) -> QuotesRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+
+    /// Sort field used for ordering.
+    pub fn sort(mut self, sort: QuotesSort) -> QuotesRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+}
+
+impl Default for QuotesRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for QuotesRequest {
+    fn get_url(&self) -> String {
+        format!("/v3/quotes/{}", self.ticker)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)?;
+        validation::limit(&self.parameters, 50000)?;
+        validation::range::<NanoTimestamp>(&self.parameters, "timestamp")
+    }
+}
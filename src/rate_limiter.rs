@@ -0,0 +1,97 @@
+use crate::error::PolygonError;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Maximum number of requests which may be sent per period.
+/// The free tier of Polygon.io allows 5 requests per minute.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    requests: u32,
+    period: Duration,
+    fail_fast: bool,
+}
+
+impl RateLimit {
+    pub fn new(requests: u32, period: Duration) -> Self {
+        RateLimit {
+            requests: requests.max(1),
+            period: period.max(Duration::from_millis(1)),
+            fail_fast: false,
+        }
+    }
+
+    pub fn per_second(requests: u32) -> Self {
+        RateLimit::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        RateLimit::new(requests, Duration::from_secs(60))
+    }
+
+    /// Return `PolygonError::LocalRateLimited` when the limit is reached instead of waiting for capacity.
+    pub fn fail_fast(mut self) -> RateLimit {
+        self.fail_fast = true;
+        self
+    }
+}
+
+/// Token bucket enforcing a `RateLimit`. Shared by all clones of a client.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        RateLimiter {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: limit.requests as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes a token from the bucket, waiting until one is available unless the limit is set to fail fast.
+    pub(crate) async fn acquire(&self) -> Result<(), PolygonError> {
+        loop {
+            let wait = match self.try_acquire() {
+                None => return Ok(()),
+                Some(wait) => wait,
+            };
+
+            if self.limit.fail_fast {
+                return Err(PolygonError::LocalRateLimited { retry_after: wait });
+            }
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token if one is available, otherwise returns how long until the next one is.
+    fn try_acquire(&self) -> Option<Duration> {
+        let capacity = self.limit.requests as f64;
+        let tokens_per_sec = capacity / self.limit.period.as_secs_f64();
+
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * tokens_per_sec).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - bucket.tokens) / tokens_per_sec))
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::common::TickerType;
+use crate::conditions::{Condition, DataType, UpdateRule};
+use crate::exchanges::Exchange;
+use crate::ticker_types::TickerTypeResult;
+use std::collections::HashMap;
+
+/// In-memory lookup tables of the ticker types, exchanges and conditions of one asset class,
+/// to resolve the codes and ids in tickers, trades and quotes without another request.
+/// Load them with [load_reference_tables](crate::polygon_client::PolygonClient::load_reference_tables) and keep them for the session.
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceTables {
+    ticker_types: HashMap<TickerType, TickerTypeResult>,
+    exchanges: HashMap<u32, Exchange>,
+    exchange_ids_by_mic: HashMap<String, u32>,
+    trade_conditions: HashMap<u32, Condition>,
+    quote_conditions: HashMap<u32, Condition>,
+}
+
+impl ReferenceTables {
+    pub fn new(
+        ticker_types: impl IntoIterator<Item = TickerTypeResult>,
+        exchanges: impl IntoIterator<Item = Exchange>,
+        conditions: impl IntoIterator<Item = Condition>,
+    ) -> Self {
+        let mut tables = ReferenceTables::default();
+
+        for ticker_type in ticker_types {
+            tables.ticker_types.insert(ticker_type.code.clone(), ticker_type);
+        }
+
+        for exchange in exchanges {
+            if let Some(mic) = &exchange.mic {
+                tables.exchange_ids_by_mic.insert(mic.clone(), exchange.id);
+            }
+            tables.exchanges.insert(exchange.id, exchange);
+        }
+
+        // Trade and quote conditions have overlapping ids, so they are kept apart.
+        for condition in conditions {
+            if condition.data_types.contains(&DataType::Trade) {
+                tables.trade_conditions.insert(condition.id, condition.clone());
+            }
+            if condition
+                .data_types
+                .iter()
+                .any(|data_type| matches!(data_type, DataType::BBO | DataType::NBBO))
+            {
+                tables.quote_conditions.insert(condition.id, condition);
+            }
+        }
+
+        tables
+    }
+
+    /// The description of a ticker type, e.g. `Common Stock` for `CS`.
+    pub fn ticker_type(&self, code: &TickerType) -> Option<&TickerTypeResult> {
+        self.ticker_types.get(code)
+    }
+
+    /// The exchange with the id used in trades and quotes.
+    pub fn exchange(&self, id: u32) -> Option<&Exchange> {
+        self.exchanges.get(&id)
+    }
+
+    /// The exchange with the MIC used as `primary_exchange` of tickers, e.g. `XNAS`.
+    pub fn exchange_by_mic(&self, mic: &str) -> Option<&Exchange> {
+        self.exchange_ids_by_mic.get(mic).and_then(|id| self.exchanges.get(id))
+    }
+
+    /// The condition with the id used in the `conditions` of trades.
+    pub fn trade_condition(&self, id: u32) -> Option<&Condition> {
+        self.trade_conditions.get(&id)
+    }
+
+    /// The condition with the id used in the `conditions` of quotes.
+    pub fn quote_condition(&self, id: u32) -> Option<&Condition> {
+        self.quote_conditions.get(&id)
+    }
+
+    /// How a trade with these conditions updates the consolidated aggregates: only what all of its conditions allow.
+    /// Unknown conditions and conditions without update rules don't restrict the trade.
+    pub fn trade_updates(&self, conditions: &[u32]) -> UpdateRule {
+        let allowed = UpdateRule {
+            updates_high_low: true,
+            updates_open_close: true,
+            updates_volume: true,
+        };
+
+        conditions
+            .iter()
+            .filter_map(|id| self.trade_condition(*id)?.update_rules.as_ref())
+            .fold(allowed, |allowed, rules| UpdateRule {
+                updates_high_low: allowed.updates_high_low && rules.consolidated.updates_high_low,
+                updates_open_close: allowed.updates_open_close && rules.consolidated.updates_open_close,
+                updates_volume: allowed.updates_volume && rules.consolidated.updates_volume,
+            })
+    }
+}
@@ -1,54 +1,111 @@
+use crate::error::PolygonError;
+use crate::rate_limiter::{RateLimit, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::transport::{HttpRequest, ReqwestTransport, Transport};
+use crate::validation::ValidationError;
+use std::sync::Arc;
+
+/// Maximum length of a successful response body which is checked for an error report before it is decoded.
+const ERROR_BODY_MAX_LEN: usize = 1024;
+
 pub trait RequestType {
     fn get_url(&self) -> String;
     fn get_query(&self) -> Vec<(&String, &String)>;
+
+    /// Checks the request before it is sent, so invalid requests fail without using up the rate limit.
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
 }
 
+#[derive(Clone)]
 pub struct RestClient {
     api_url: String,
     auth_key: String,
-    client: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl RestClient {
-    pub fn new(api_url: impl Into<String>, auth_key: impl Into<String>, timeout: Option<core::time::Duration>) -> Result<Self, String> {
-        let mut client = reqwest::ClientBuilder::new();
-
-        if let Some(timeout) = timeout {
-            client = client.timeout(timeout);
-        }
+    pub fn new(api_url: impl Into<String>, auth_key: impl Into<String>, timeout: Option<core::time::Duration>) -> Result<Self, PolygonError> {
+        let transport = ReqwestTransport::new(timeout)?;
+        Ok(RestClient::with_transport(api_url, auth_key, Arc::new(transport)))
+    }
 
-        Ok(RestClient {
+    /// Create a client which sends its requests through `transport`, e.g. a `MockTransport` in tests.
+    pub fn with_transport(api_url: impl Into<String>, auth_key: impl Into<String>, transport: Arc<dyn Transport>) -> Self {
+        RestClient {
             api_url: api_url.into(),
             auth_key: auth_key.into(),
-            client: client.build().unwrap(),
-        })
+            transport,
+            rate_limiter: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Limit the number of requests sent by this client and all of its clones.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> RestClient {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(rate_limit)));
+        self
+    }
+
+    /// Retry failed requests according to the policy. By default requests are sent only once.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> RestClient {
+        self.retry_policy = Some(retry_policy);
+        self
     }
 
-    pub(crate) async fn send_request<ResponseType>(&self, request: &impl RequestType) -> Result<ResponseType, reqwest::Error>
+    pub(crate) async fn send_request<ResponseType>(&self, request: &impl RequestType) -> Result<ResponseType, PolygonError>
     where
         ResponseType: serde::de::DeserializeOwned,
     {
-        let uri = request.get_url();
-        let query_params = request.get_query();
-        let res = self
-            .client
-            .get(format!("{}{}", self.api_url, uri))
-            .bearer_auth(&self.auth_key)
-            .query(&query_params)
-            .send()
-            .await;
-
-        match res {
-            Ok(res) => {
-                if res.status() == 200 {
-                    // let text = &res.text().await.unwrap();
-                    // Ok(text.to_string())
-                    res.json::<ResponseType>().await
-                } else {
-                    Err(res.error_for_status().err().unwrap())
-                }
+        request.validate()?;
+
+        let mut attempt = 1;
+        let body = loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await?;
+            }
+
+            match self.fetch(request).await {
+                Ok(body) => break body,
+                Err(e) => match self.retry_policy.and_then(|retry_policy| retry_policy.delay(attempt, &e)) {
+                    Some(delay) => {
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
             }
-            Err(e) => Err(e),
+        };
+
+        // A small body may be an error report which also decodes into a response type whose fields all have defaults.
+        // Larger bodies are only checked for an error report when they fail to decode, to avoid parsing them twice.
+        if body.len() <= ERROR_BODY_MAX_LEN {
+            if let Some(err) = PolygonError::from_body(&body) {
+                return Err(err);
+            }
+        }
+
+        serde_json::from_str::<ResponseType>(&body).map_err(|e| PolygonError::from_body(&body).unwrap_or_else(|| PolygonError::deserialize(e, &body)))
+    }
+
+    /// Sends the request once, returning the body of a successful response.
+    async fn fetch(&self, request: &impl RequestType) -> Result<String, PolygonError> {
+        let request = HttpRequest {
+            api_url: self.api_url.clone(),
+            path: request.get_url(),
+            query: request.get_query().into_iter().map(|(key, value)| (key.clone(), value.clone())).collect(),
+            auth_key: self.auth_key.clone(),
+        };
+
+        let res = self.transport.send(&request).await?;
+
+        if res.status != 200 {
+            return Err(PolygonError::from_response(res.status, res.retry_after, &res.body));
         }
+
+        Ok(res.body)
     }
 }
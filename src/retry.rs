@@ -0,0 +1,80 @@
+use crate::error::PolygonError;
+use std::time::Duration;
+
+/// How failed requests are retried. Rate limited requests (429), gateway errors (502, 503, 504)
+/// and connection resets are retried with exponential backoff, other errors are returned immediately.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+
+    /// Total number of attempts including the first one, default is 3.
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Backoff before the first retry, doubled for every following retry. Default is 500ms.
+    pub fn base_backoff(mut self, base_backoff: Duration) -> RetryPolicy {
+        self.base_backoff = base_backoff;
+        self
+    }
+
+    /// Upper bound of the backoff between two attempts, default is 30s.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> RetryPolicy {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Randomize the backoff between half and all of its value, default is true.
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns how long to wait before retrying after `attempt` failed with `error`,
+    /// or `None` if the request should not be retried.
+    pub(crate) fn delay(&self, attempt: u32, error: &PolygonError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !error.is_retryable() {
+            return None;
+        }
+
+        if let PolygonError::RateLimited {
+            retry_after: Some(retry_after),
+        } = error
+        {
+            return Some(*retry_after);
+        }
+
+        let backoff = self
+            .base_backoff
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            Some(backoff.mul_f64(0.5 + fastrand::f64() / 2.0))
+        } else {
+            Some(backoff)
+        }
+    }
+}
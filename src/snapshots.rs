@@ -0,0 +1,262 @@
+use crate::common::Money;
+use crate::last_quote::LastQuote;
+use crate::last_trade::LastTrade;
+use crate::rest_client::RequestType;
+use crate::utils::{millis_format, money_format, nanos_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::OffsetDateTime;
+
+/// /v2/snapshot/locale/us/markets/stocks/tickers
+/// Get the most up-to-date market data for all traded stock symbols.
+const SNAPSHOT_TICKERS_PATH: &str = "/v2/snapshot/locale/us/markets/stocks/tickers";
+
+/// /v2/snapshot/locale/us/markets/stocks/tickers/{ticker}
+/// Get the most up-to-date market data for a single traded stock ticker.
+const SNAPSHOT_TICKER_PATH: &str = "/v2/snapshot/locale/us/markets/stocks/tickers/{ticker}";
+
+/// /v2/snapshot/locale/us/markets/stocks/{direction}
+/// Get the most up-to-date market data for the current top 20 gainers or losers of the day in the stocks market.
+const SNAPSHOT_GAINERS_LOSERS_PATH: &str = "/v2/snapshot/locale/us/markets/stocks/{direction}";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SnapshotTickersResponse {
+    /// The total number of results for this request.
+    #[serde(default = "i32::default")]
+    pub count: i32,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+    #[serde(default = "Vec::default")]
+    pub tickers: Vec<TickerSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SnapshotTickerResponse {
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+    pub ticker: TickerSnapshot,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerSnapshot {
+    /// The exchange symbol that this item is traded under.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// The value of the change from the previous day.
+    #[serde(rename = "todaysChange", default, with = "money_format")]
+    pub todays_change: Money,
+    /// The percentage change since the previous day.
+    #[serde(rename = "todaysChangePerc", default = "f64::default")]
+    pub todays_change_perc: f64,
+    /// The last updated timestamp.
+    #[serde(default, with = "nanos_format::option")]
+    pub updated: Option<OffsetDateTime>,
+    /// The most recent daily bar for this ticker.
+    pub day: Option<SnapshotBar>,
+    /// The previous day bar for this ticker.
+    #[serde(rename = "prevDay")]
+    pub prev_day: Option<SnapshotBar>,
+    /// The most recent minute bar for this ticker.
+    pub min: Option<MinuteSnapshot>,
+    /// The most recent trade for this ticker.
+    #[serde(rename = "lastTrade")]
+    pub last_trade: Option<LastTrade>,
+    /// The most recent quote for this ticker.
+    #[serde(rename = "lastQuote")]
+    pub last_quote: Option<LastQuote>,
+}
+
+/// The daily bar of a snapshot. All values are zero before the first trade of the day.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SnapshotBar {
+    /// The open price for the symbol in the given time period.
+    #[serde(rename = "o", default, with = "money_format")]
+    pub open: Money,
+    /// The highest price for the symbol in the given time period.
+    #[serde(rename = "h", default, with = "money_format")]
+    pub high: Money,
+    /// The lowest price for the symbol in the given time period.
+    #[serde(rename = "l", default, with = "money_format")]
+    pub low: Money,
+    /// The close price for the symbol in the given time period.
+    #[serde(rename = "c", default, with = "money_format")]
+    pub close: Money,
+    /// The trading volume of the symbol in the given time period.
+    #[serde(rename = "v", default = "f64::default")]
+    pub volume: f64,
+    /// The volume weighted average price.
+    #[serde(rename = "vw", default, with = "money_format::option")]
+    pub vwap: Option<Money>,
+}
+
+/// The minute bar of a snapshot. Empty before the first trade of the session.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MinuteSnapshot {
+    /// The accumulated volume of the day.
+    #[serde(rename = "av", default = "f64::default")]
+    pub accumulated_volume: f64,
+    #[serde(flatten)]
+    pub bar: SnapshotBar,
+    /// The number of transactions in the minute.
+    #[serde(rename = "n", default)]
+    pub transactions: Option<u64>,
+    /// The start of the minute.
+    #[serde(rename = "t", default, with = "millis_format::option")]
+    pub timestamp: Option<OffsetDateTime>,
+}
+
+#[derive(Debug)]
+pub struct SnapshotTickersRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl SnapshotTickersRequest {
+    pub fn new() -> Self {
+        SnapshotTickersRequest {
+            path: SNAPSHOT_TICKERS_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Only return snapshots of these tickers. By default all tickers are returned.
+    pub fn tickers<T: AsRef<str>>(mut self, tickers: impl IntoIterator<Item = T>) -> SnapshotTickersRequest {
+        let tickers: Vec<String> = tickers.into_iter().map(|ticker| ticker.as_ref().to_string()).collect();
+        self.parameters.insert("tickers".to_string(), tickers.join(","));
+        self
+    }
+
+    /// Include OTC securities in the response. Default is false.
+    pub fn include_otc(mut self, include_otc: bool) -> SnapshotTickersRequest {
+        self.parameters.insert("include_otc".to_string(), include_otc.to_string());
+        self
+    }
+}
+
+impl Default for SnapshotTickersRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for SnapshotTickersRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct SnapshotTickerRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+}
+
+impl SnapshotTickerRequest {
+    pub fn new() -> Self {
+        SnapshotTickerRequest {
+            path: SNAPSHOT_TICKER_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> SnapshotTickerRequest {
+        self.ticker = ticker.into();
+        self
+    }
+}
+
+impl Default for SnapshotTickerRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for SnapshotTickerRequest {
+    fn get_url(&self) -> String {
+        format!("/v2/snapshot/locale/us/markets/stocks/tickers/{}", self.ticker)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Gainers,
+    Losers,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct SnapshotGainersLosersRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub direction: Direction,
+}
+
+impl SnapshotGainersLosersRequest {
+    /// The top 20 gainers, see [direction](Self::direction) for the losers.
+    pub fn new() -> Self {
+        SnapshotGainersLosersRequest {
+            path: SNAPSHOT_GAINERS_LOSERS_PATH,
+            parameters: HashMap::new(),
+            direction: Direction::Gainers,
+        }
+    }
+
+    /// Whether to get the top gainers or the top losers. Default is `Gainers`.
+    pub fn direction(mut self, direction: Direction) -> SnapshotGainersLosersRequest {
+        self.direction = direction;
+        self
+    }
+
+    /// Include OTC securities in the response. Default is false.
+    pub fn include_otc(mut self, include_otc: bool) -> SnapshotGainersLosersRequest {
+        self.parameters.insert("include_otc".to_string(), include_otc.to_string());
+        self
+    }
+}
+
+impl Default for SnapshotGainersLosersRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for SnapshotGainersLosersRequest {
+    fn get_url(&self) -> String {
+        format!("/v2/snapshot/locale/us/markets/stocks/{}", self.direction)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
@@ -0,0 +1,139 @@
+use crate::common::Order;
+use crate::filter::RangeFilter;
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::utils::date_format;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::Date;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SplitsResponse {
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "Vec::default")]
+    pub results: Vec<Split>,
+    pub status: String,
+}
+
+impl Paginated for SplitsResponse {
+    type Item = Split;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<Split> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Split {
+    #[serde(default = "String::default")]
+    pub id: String,
+    #[serde(with = "date_format")]
+    pub execution_date: Date,
+    /// The first number of the split ratio, e.g. 1 in a 4-for-1 split.
+    pub split_from: f64,
+    /// The second number of the split ratio, e.g. 4 in a 4-for-1 split.
+    pub split_to: f64,
+    #[serde(default = "String::default")]
+    pub ticker: String,
+}
+
+impl Split {
+    /// The number of shares after the split per share before it, e.g. 4 for a 4-for-1 split and 0.1 for a 1-for-10 reverse split.
+    /// Divide prices before the execution date by the ratio to adjust them for the split.
+    pub fn ratio(&self) -> f64 {
+        self.split_to / self.split_from
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Deserialize)]
+pub enum SplitSort {
+    Execution_Date,
+    Ticker,
+}
+
+impl fmt::Display for SplitSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct SplitRequest {
+    pub parameters: HashMap<String, String>,
+}
+
+impl Default for SplitRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for SplitRequest {
+    fn get_url(&self) -> String {
+        "/v3/reference/splits".to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 1000)?;
+        validation::range::<String>(&self.parameters, "ticker")?;
+        validation::range::<Date>(&self.parameters, "execution_date")
+    }
+}
+
+impl SplitRequest {
+    pub fn new() -> Self {
+        SplitRequest {
+            parameters: Default::default(),
+        }
+    }
+
+    /// Filter by ticker symbol, either an exact symbol or a range. Defaults to empty string which queries all tickers.
+    pub fn ticker(mut self, ticker: impl Into<RangeFilter<String>>) -> SplitRequest {
+        ticker.into().apply("ticker", &mut self.parameters);
+        self
+    }
+
+    /// Filter by execution date, either an exact date or a range like `date!(2020 - 01 - 01)..`.
+    pub fn execution_date(mut self, execution_date: impl Into<RangeFilter<Date>>) -> SplitRequest {
+        execution_date.into().apply("execution_date", &mut self.parameters);
+        self
+    }
+
+    /// Only return reverse splits if true, or only forward splits if false. By default both are returned.
+    pub fn reverse_split(mut self, reverse_split: bool) -> SplitRequest {
+        self.parameters.insert("reverse_split".to_string(), reverse_split.to_string());
+        self
+    }
+
+    pub fn order(mut self, order: Order) -> SplitRequest {
+        self.parameters.insert("order".to_string(), order.to_string());
+        self
+    }
+
+    /// Limit the number of results returned, default is 10 and max is 1000.
+    pub fn limit(mut self, limit: u32) -> SplitRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+
+    /// Sort field used for ordering.
+    pub fn sort(mut self, sort: SplitSort) -> SplitRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+}
@@ -1,12 +1,14 @@
+use crate::common::{Locale, Market, Money, TickerType};
 use crate::rest_client::*;
-use serde::Deserialize;
+use crate::utils::{count_format, date_format, money_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use time::Date;
 
 /// /v3/reference/tickers/{ticker}
 /// Get a single ticker supported by Polygon.io.
 /// This response will have detailed information about the ticker and the company behind it.
-
 const TICKER_DETAILS_PATH: &str = "/v3/reference/tickers/{ticker}";
 
 #[derive(Debug)]
@@ -38,6 +40,12 @@ impl TickerDetailsRequest {
     }
 }
 
+impl Default for TickerDetailsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RequestType for TickerDetailsRequest {
     fn get_url(&self) -> String {
         format!("/v3/reference/tickers/{}", self.ticker)
@@ -46,89 +54,88 @@ impl RequestType for TickerDetailsRequest {
     fn get_query(&self) -> Vec<(&String, &String)> {
         self.parameters.iter().collect()
     }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)
+    }
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TickerDetailsResponse {
-    request_id: String,
-    results: TickerDetailsResult,
-    status: String,
+    pub request_id: String,
+    pub results: TickerDetailsResult,
+    pub status: String,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TickerDetailsResult {
     #[serde(default = "bool::default")]
-    active: bool,
-    address: Option<Address>,
-    branding: Option<Branding>,
+    pub active: bool,
+    pub address: Option<Address>,
+    pub branding: Option<Branding>,
     #[serde(default = "String::default")]
-    cik: String,
+    pub cik: String,
     #[serde(default = "String::default")]
-    composite_figi: String,
+    pub composite_figi: String,
     #[serde(default = "String::default")]
-    currency_name: String,
+    pub currency_name: String,
     #[serde(default = "String::default")]
-    description: String,
+    pub description: String,
     #[serde(default = "String::default")]
-    homepage_url: String,
+    pub homepage_url: String,
+    #[serde(default, with = "date_format::option")]
+    pub list_date: Option<Date>,
+    #[serde(default)]
+    pub locale: Locale,
+    #[serde(default)]
+    pub market: Market,
+    #[serde(default, with = "money_format")]
+    pub market_cap: Money,
     #[serde(default = "String::default")]
-    list_date: String,
+    pub name: String,
     #[serde(default = "String::default")]
-    locale: String,
+    pub phone_number: String,
     #[serde(default = "String::default")]
-    market: String,
-    #[serde(default = "f32::default")]
-    market_cap: f32,
-    #[serde(default = "String::default")]
-    name: String,
-    #[serde(default = "String::default")]
-    phone_number: String,
-    #[serde(default = "String::default")]
-    primary_exchange: String,
+    pub primary_exchange: String,
     #[serde(default = "u32::default")]
-    round_lot: u32,
+    pub round_lot: u32,
     #[serde(default = "String::default")]
-    share_class_figi: String,
-    #[serde(default = "i64::default")]
-    share_class_shares_outstanding: i64,
-    #[serde(default = "u32::default")]
-    sic_code: u32,
+    pub share_class_figi: String,
+    #[serde(default, with = "count_format")]
+    pub share_class_shares_outstanding: u64,
     #[serde(default = "String::default")]
-    sic_description: String,
+    pub sic_code: String,
     #[serde(default = "String::default")]
-    ticker: String,
+    pub sic_description: String,
     #[serde(default = "String::default")]
-    ticker_root: String,
-    #[serde(default = "u32::default")]
-    total_employees: u32,
+    pub ticker: String,
     #[serde(default = "String::default")]
-    r#type: String,
+    pub ticker_root: String,
     #[serde(default = "u32::default")]
-    weighted_shares_outstanding: u32,
+    pub total_employees: u32,
+    pub r#type: Option<TickerType>,
+    #[serde(default, with = "count_format")]
+    pub weighted_shares_outstanding: u64,
     #[serde(default = "String::default")]
-    source_feed: String,
+    pub source_feed: String,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Address {
     #[serde(default = "String::default")]
-    address1: String,
+    pub address1: String,
     #[serde(default = "String::default")]
-    city: String,
+    pub city: String,
     #[serde(default = "String::default")]
-    state: String,
+    pub state: String,
     #[serde(default = "String::default")]
-    postal_code: String,
+    pub postal_code: String,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Branding {
     #[serde(default = "String::default")]
-    icon_url: String,
+    pub icon_url: String,
     #[serde(default = "String::default")]
-    logo_url: String,
+    pub logo_url: String,
 }
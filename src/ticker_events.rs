@@ -1 +1,127 @@
+use crate::rest_client::RequestType;
+use crate::utils::date_format;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::Date;
 
+/// /vX/reference/tickers/{id}/events
+/// Get a timeline of events for the entity associated with the given ticker, CUSIP, or Composite FIGI.
+const TICKER_EVENTS_PATH: &str = "/vX/reference/tickers/{id}/events";
+
+#[derive(Debug)]
+pub struct TickerEventsRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub id: String,
+}
+
+impl TickerEventsRequest {
+    pub fn new() -> Self {
+        TickerEventsRequest {
+            path: TICKER_EVENTS_PATH,
+            parameters: HashMap::new(),
+            id: String::new(),
+        }
+    }
+
+    /// Identifier of an asset: a ticker, a CUSIP or a Composite FIGI.
+    /// Events of a ticker are those of the entity currently using it, e.g. `META` and `FB` return the same events.
+    pub fn id(mut self, id: impl Into<String>) -> TickerEventsRequest {
+        self.id = id.into();
+        self
+    }
+
+    /// Only return events of these types. By default all types are returned.
+    pub fn types(mut self, types: impl IntoIterator<Item = TickerEventType>) -> TickerEventsRequest {
+        let types: Vec<String> = types.into_iter().map(|r#type| r#type.to_string()).collect();
+        self.parameters.insert("types".to_string(), types.join(","));
+        self
+    }
+}
+
+impl Default for TickerEventsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for TickerEventsRequest {
+    fn get_url(&self) -> String {
+        format!("/vX/reference/tickers/{}/events", self.id)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("id", &self.id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerEventType {
+    TickerChange,
+}
+
+impl fmt::Display for TickerEventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TickerEventType::TickerChange => write!(f, "ticker_change"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerEventsResponse {
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    pub results: TickerEventsResult,
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerEventsResult {
+    /// The name of the entity.
+    #[serde(default = "String::default")]
+    pub name: String,
+    /// The composite OpenFIGI number of the entity.
+    #[serde(default = "String::default")]
+    pub composite_figi: String,
+    /// The CIK number of the entity.
+    #[serde(default = "String::default")]
+    pub cik: String,
+    /// The events of the entity, newest first.
+    #[serde(default = "Vec::default")]
+    pub events: Vec<TickerEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerEvent {
+    /// The date the event took effect.
+    #[serde(with = "date_format")]
+    pub date: Date,
+    #[serde(flatten)]
+    pub event: TickerEventData,
+}
+
+/// The data of an event, decoded by its `type`. Types added after this client was written deserialize to `Unknown`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TickerEventData {
+    TickerChange {
+        ticker_change: TickerChange,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerChange {
+    /// The ticker the entity used from the date of the event.
+    pub ticker: String,
+}
@@ -1,61 +1,74 @@
 use crate::common::Order;
+use crate::filter::RangeFilter;
+use crate::pagination::Paginated;
 use crate::rest_client::RequestType;
-use serde::Deserialize;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
+use time::OffsetDateTime;
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TickerNewsResponse {
-    status: String,
-    request_id: String,
-    count: i32,
+    pub status: String,
+    pub request_id: String,
+    pub count: i32,
     #[serde(default = "String::default")]
-    previous_url: String,
+    pub previous_url: String,
     #[serde(default = "String::default")]
-    next_url: String,
+    pub next_url: String,
     #[serde(default = "Vec::default")]
-    results: Vec<TickerNewsResults>,
+    pub results: Vec<TickerNewsResults>,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+impl Paginated for TickerNewsResponse {
+    type Item = TickerNewsResults;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<TickerNewsResults> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TickerNewsResults {
     #[serde(default = "String::default")]
-    amp_url: String,
+    pub amp_url: String,
     #[serde(default = "String::default")]
-    article_url: String,
+    pub article_url: String,
     #[serde(default = "String::default")]
-    author: String,
+    pub author: String,
     #[serde(default = "String::default")]
-    description: String,
+    pub description: String,
     #[serde(default = "String::default")]
-    id: String,
+    pub id: String,
     #[serde(default = "String::default")]
-    image_url: String,
+    pub image_url: String,
     #[serde(default = "Vec::default")]
-    keywords: Vec<String>,
-    #[serde(default = "String::default")]
-    published_utc: String,
-    publisher: Option<Publisher>,
+    pub keywords: Vec<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published_utc: OffsetDateTime,
+    pub publisher: Option<Publisher>,
     #[serde(default = "Vec::default")]
-    tickers: Vec<String>,
+    pub tickers: Vec<String>,
     #[serde(default = "String::default")]
-    title: String,
+    pub title: String,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Publisher {
     #[serde(default = "String::default")]
-    favicon_url: String,
+    pub favicon_url: String,
     #[serde(default = "String::default")]
-    homepage_url: String,
+    pub homepage_url: String,
     #[serde(default = "String::default")]
-    logo_url: String,
+    pub logo_url: String,
     #[serde(default = "String::default")]
-    name: String,
+    pub name: String,
 }
 
 #[derive(Debug)]
@@ -63,6 +76,12 @@ pub struct TickerNewsRequest {
     pub parameters: HashMap<String, String>,
 }
 
+impl Default for TickerNewsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RequestType for TickerNewsRequest {
     fn get_url(&self) -> String {
         "/v2/reference/news".to_string()
@@ -71,6 +90,12 @@ impl RequestType for TickerNewsRequest {
     fn get_query(&self) -> Vec<(&String, &String)> {
         self.parameters.iter().collect()
     }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 1000)?;
+        validation::range::<String>(&self.parameters, "ticker")?;
+        validation::range::<OffsetDateTime>(&self.parameters, "published_utc")
+    }
 }
 
 impl TickerNewsRequest {
@@ -80,54 +105,15 @@ impl TickerNewsRequest {
         }
     }
 
-    /// Specify a ticker symbol. Defaults to empty string which queries all tickers.
-    pub fn ticker(mut self, ticker: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("ticker".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_gt(mut self, ticker: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("ticker.gt".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_gte(mut self, ticker: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("ticker.gte".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_lt(mut self, ticker: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("ticker.lt".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_lte(mut self, ticker: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("ticker.lte".to_string(), ticker.into());
-        self
-    }
-
-    pub fn published_utc(mut self, published_utc: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("published_utc".to_string(), published_utc.into());
-        self
-    }
-
-    pub fn published_utc_gt(mut self, published_utc: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("published_utc.gt".to_string(), published_utc.into());
-        self
-    }
-
-    pub fn published_utc_gte(mut self, published_utc: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("published_utc.gte".to_string(), published_utc.into());
-        self
-    }
-
-    pub fn published_utc_lt(mut self, published_utc: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("published_utc.lt".to_string(), published_utc.into());
+    /// Specify a ticker symbol or a range of symbols. Defaults to empty string which queries all tickers.
+    pub fn ticker(mut self, ticker: impl Into<RangeFilter<String>>) -> TickerNewsRequest {
+        ticker.into().apply("ticker", &mut self.parameters);
         self
     }
 
-    pub fn published_utc_lte(mut self, published_utc: impl Into<String>) -> TickerNewsRequest {
-        self.parameters.insert("published_utc.lte".to_string(), published_utc.into());
+    /// Filter by publish time, either an exact time or a range.
+    pub fn published_utc(mut self, published_utc: impl Into<RangeFilter<OffsetDateTime>>) -> TickerNewsRequest {
+        published_utc.into().apply("published_utc", &mut self.parameters);
         self
     }
 
@@ -139,7 +125,6 @@ impl TickerNewsRequest {
 
     /// Limit the number of results returned, default is 100 and max is 1000.
     pub fn limit(mut self, limit: u32) -> TickerNewsRequest {
-        let limit = if limit > 1000 { 1000 } else { limit };
         self.parameters.insert("limit".to_string(), limit.to_string());
         self
     }
@@ -0,0 +1,79 @@
+pub use crate::common::{AssetClass, Locale, TickerType};
+use crate::rest_client::RequestType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// /v3/reference/tickers/types
+/// List all ticker types that Polygon.io has.
+const TICKER_TYPES_PATH: &str = "/v3/reference/tickers/types";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerTypesResponse {
+    /// The total number of results for this request.
+    #[serde(default = "i32::default")]
+    pub count: i32,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    #[serde(default = "Vec::default")]
+    pub results: Vec<TickerTypeResult>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TickerTypeResult {
+    #[serde(default)]
+    pub asset_class: AssetClass,
+    /// The code of the type, as used in the `type` of a ticker.
+    pub code: TickerType,
+    /// A short description of the type, e.g. `Common Stock`.
+    #[serde(default = "String::default")]
+    pub description: String,
+    #[serde(default)]
+    pub locale: Locale,
+}
+
+#[derive(Debug)]
+pub struct TickerTypesRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl TickerTypesRequest {
+    pub fn new() -> Self {
+        TickerTypesRequest {
+            path: TICKER_TYPES_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Filter by asset class.
+    pub fn asset_class(mut self, asset_class: AssetClass) -> TickerTypesRequest {
+        self.parameters.insert("asset_class".to_string(), asset_class.to_string());
+        self
+    }
+
+    /// Filter by locale.
+    pub fn locale(mut self, locale: Locale) -> TickerTypesRequest {
+        self.parameters.insert("locale".to_string(), locale.to_string());
+        self
+    }
+}
+
+impl Default for TickerTypesRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for TickerTypesRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+}
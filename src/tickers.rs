@@ -1,88 +1,101 @@
-#![allow(dead_code)]
-
-pub use crate::common::{Market, Order};
+pub use crate::common::{Locale, Market, Order, TickerType};
+use crate::filter::RangeFilter;
+use crate::pagination::Paginated;
 use crate::rest_client::RequestType;
-use serde::Deserialize;
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
-use time::Date;
+use time::{Date, OffsetDateTime};
 
 /// https://polygon.io/docs/stocks/get_v3_reference_tickers
 const TICKERS_PATH: &str = "/v3/reference/tickers";
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TickersResponse {
     /// The total number of results for this request.
-    count: i32,
+    pub count: i32,
     /// If present, this value can be used to fetch the next page of data.
     #[serde(default = "String::default")]
-    next_url: String,
+    pub next_url: String,
     /// A request id assigned by the server.
     #[serde(default = "String::default")]
-    request_id: String,
+    pub request_id: String,
     /// An array of tickers that match your query.
     #[serde(default = "Vec::default")]
-    results: Vec<TickersResult>,
+    pub results: Vec<TickersResult>,
     /// The status of this request's response.
     #[serde(default = "String::default")]
-    status: String,
+    pub status: String,
+}
+
+impl Paginated for TickersResponse {
+    type Item = TickersResult;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<TickersResult> {
+        self.results
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TickersResult {
     /// Whether or not the asset is actively traded. False means the asset has been delisted.
     #[serde(default = "bool::default")]
-    active: bool,
+    pub active: bool,
 
     /// The CIK number for this ticker. Find more information here.
     #[serde(default = "String::default")]
-    cik: String,
+    pub cik: String,
 
     /// The composite OpenFIGI number for this ticker. Find more information here
     #[serde(default = "String::default")]
-    composite_figi: String,
+    pub composite_figi: String,
 
     /// The name of the currency that this asset is traded with.
     #[serde(default = "String::default")]
-    currency_name: String,
+    pub currency_name: String,
 
     /// The last date that the asset was traded.
-    #[serde(default = "String::default")]
-    delisted_utc: String,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub delisted_utc: Option<OffsetDateTime>,
 
     /// The information is accurate up to this time.
-    #[serde(default = "String::default")]
-    last_updated_utc: String,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub last_updated_utc: Option<OffsetDateTime>,
 
     /// The Locale of the asset.
-    #[serde(default = "String::default")]
-    locale: String, // enum [us, global]
+    #[serde(default)]
+    pub locale: Locale,
 
     /// The market type of the asset.
-    #[serde(default = "String::default")]
-    market: String, // *enum [stocks, crypto, fx, otc, indices]
+    #[serde(default)]
+    pub market: Market,
 
     /// The name of the asset. For stocks/equities this will be the companies registered name.
     /// For crypto/fx this will be the name of the currency or coin pair.
     #[serde(default = "String::default")]
-    name: String,
+    pub name: String,
 
     /// The ISO code of the primary listing exchange for this asset.
     #[serde(default = "String::default")]
-    primary_exchange: String,
+    pub primary_exchange: String,
 
     /// The share Class OpenFIGI number for this ticker. Find more information here
     #[serde(default = "String::default")]
-    share_class_figi: String,
+    pub share_class_figi: String,
 
     /// The exchange symbol that this item is traded under.
     #[serde(default = "String::default")]
-    ticker: String,
+    pub ticker: String,
 
     /// The type of the asset. Find the types that we support via our Ticker Types API.
-    #[serde(default = "String::default")]
-    r#type: String,
+    /// Not set for crypto and fx tickers.
+    pub r#type: Option<TickerType>,
 }
 
 // scheme://host:port/path?queryString#fragment
@@ -108,29 +121,9 @@ impl TickersRequest {
         request
     }
 
-    /// Specify a ticker symbol. Defaults to empty string which queries all tickers.
-    pub fn ticker(mut self, ticker: impl Into<String>) -> TickersRequest {
-        self.parameters.insert("ticker".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_gt(mut self, ticker: impl Into<String>) -> TickersRequest {
-        self.parameters.insert("ticker.gt".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_gte(mut self, ticker: impl Into<String>) -> TickersRequest {
-        self.parameters.insert("ticker.gte".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_lt(mut self, ticker: impl Into<String>) -> TickersRequest {
-        self.parameters.insert("ticker.lt".to_string(), ticker.into());
-        self
-    }
-
-    pub fn ticker_lte(mut self, ticker: impl Into<String>) -> TickersRequest {
-        self.parameters.insert("ticker.lte".to_string(), ticker.into());
+    /// Specify a ticker symbol or a range of symbols. Defaults to empty string which queries all tickers.
+    pub fn ticker(mut self, ticker: impl Into<RangeFilter<String>>) -> TickersRequest {
+        ticker.into().apply("ticker", &mut self.parameters);
         self
     }
 
@@ -190,7 +183,6 @@ impl TickersRequest {
 
     /// Limit the number of results returned, default is 100 and max is 1000.
     pub fn limit(mut self, limit: u32) -> TickersRequest {
-        let limit = if limit > 1000 { 1000 } else { limit };
         self.parameters.insert("limit".to_string(), limit.to_string());
         self
     }
@@ -210,6 +202,11 @@ impl RequestType for TickersRequest {
     fn get_query(&self) -> Vec<(&String, &String)> {
         self.parameters.iter().collect()
     }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 1000)?;
+        validation::range::<String>(&self.parameters, "ticker")
+    }
 }
 
 #[allow(non_camel_case_types)]
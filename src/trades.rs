@@ -0,0 +1,160 @@
+use crate::common::{Money, Order, Tape};
+use crate::filter::{NanoTimestamp, RangeFilter};
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::utils::{money_format, nanos_format};
+use crate::validation::{self, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::OffsetDateTime;
+
+/// /v3/trades/{ticker}
+/// Get trades for a ticker symbol in a given time range.
+const TRADES_PATH: &str = "/v3/trades/{ticker}";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TradesResponse {
+    /// If present, this value can be used to fetch the next page of data.
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// The trades of this page.
+    #[serde(default = "Vec::default")]
+    pub results: Vec<Trade>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl Paginated for TradesResponse {
+    type Item = Trade;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<Trade> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Trade {
+    /// The trade conditions, see the Conditions endpoint for their meaning.
+    #[serde(default = "Vec::default")]
+    pub conditions: Vec<u32>,
+    /// The trade correction indicator.
+    pub correction: Option<u32>,
+    /// The id of the exchange the trade happened on, see the Exchanges endpoint for their names.
+    #[serde(default = "u32::default")]
+    pub exchange: u32,
+    /// The trade id, unique per ticker, exchange and trading day.
+    #[serde(default = "String::default")]
+    pub id: String,
+    /// The time at which the trade was generated at the exchange.
+    #[serde(default, with = "nanos_format::option")]
+    pub participant_timestamp: Option<OffsetDateTime>,
+    /// The price of the trade.
+    #[serde(with = "money_format")]
+    pub price: Money,
+    /// The sequence number of the trade, increasing per ticker and trading day.
+    #[serde(default = "u64::default")]
+    pub sequence_number: u64,
+    /// The time at which the SIP received the trade from the exchange.
+    #[serde(with = "nanos_format")]
+    pub sip_timestamp: OffsetDateTime,
+    /// The number of shares traded. Fractional for crypto.
+    pub size: f64,
+    /// The tape the trade was reported to. Not set for crypto and fx.
+    pub tape: Option<Tape>,
+    /// The id of the trade reporting facility, for trades reported off exchange.
+    pub trf_id: Option<u32>,
+    /// The time at which the trade reporting facility received the trade.
+    #[serde(default, with = "nanos_format::option")]
+    pub trf_timestamp: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TradesSort {
+    Timestamp,
+}
+
+impl fmt::Display for TradesSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct TradesRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+    pub ticker: String,
+}
+
+impl TradesRequest {
+    pub fn new() -> Self {
+        TradesRequest {
+            path: TRADES_PATH,
+            parameters: HashMap::new(),
+            ticker: String::new(),
+        }
+    }
+
+    /// The ticker symbol of the asset
+    pub fn ticker(mut self, ticker: impl Into<String>) -> TradesRequest {
+        self.ticker = ticker.into();
+        self
+    }
+
+    /// Filter by SIP timestamp, either an exact timestamp or a range like
+    /// `NanoTimestamp::from(date!(2023 - 01 - 09))..NanoTimestamp::from(date!(2023 - 01 - 10))`.
+    pub fn timestamp(mut self, timestamp: impl Into<RangeFilter<NanoTimestamp>>) -> TradesRequest {
+        timestamp.into().apply("timestamp", &mut self.parameters);
+        self
+    }
+
+    /// Order results based on the sort field.
+    pub fn order(mut self, order: Order) -> TradesRequest {
+        self.parameters.insert("order".to_string(), order.to_string());
+        self
+    }
+
+    /// Limit the number of results returned, default is 1000 and max is 50000.
+    pub fn limit(mut self, limit: u32) -> TradesRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+
+    /// Sort field used for ordering.
+    pub fn sort(mut self, sort: TradesSort) -> TradesRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+}
+
+impl Default for TradesRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for TradesRequest {
+    fn get_url(&self) -> String {
+        format!("/v3/trades/{}", self.ticker)
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::required("ticker", &self.ticker)?;
+        validation::limit(&self.parameters, 50000)?;
+        validation::range::<NanoTimestamp>(&self.parameters, "timestamp")
+    }
+}
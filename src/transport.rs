@@ -0,0 +1,167 @@
+use crate::error::PolygonError;
+use futures::future::BoxFuture;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A GET request to the Polygon.io api.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub api_url: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub auth_key: String,
+}
+
+impl HttpRequest {
+    /// The url of the request without the query string.
+    pub fn url(&self) -> String {
+        format!("{}{}", self.api_url, self.path)
+    }
+
+    /// Path and query of the request with the parameters sorted and encoded, so equal requests have equal keys
+    /// regardless of the order of their parameters. An `apiKey` parameter is left out so it never ends up in a cassette.
+    pub fn key(&self) -> String {
+        request_key(&self.path, self.query.iter().map(|(key, value)| (key.as_str(), value.as_str())))
+    }
+}
+
+fn request_key<'a>(path: &str, query: impl Iterator<Item = (&'a str, &'a str)>) -> String {
+    let mut query: Vec<(&str, &str)> = query.filter(|(name, _)| *name != "apiKey").collect();
+    query.sort();
+
+    if query.is_empty() {
+        return path.to_string();
+    }
+    // Names and values are encoded, so a `&` or `=` in a value can't be mistaken for another parameter.
+    let query = url::form_urlencoded::Serializer::new(String::new()).extend_pairs(query).finish();
+    format!("{}?{}", path, query)
+}
+
+/// The status and body of a response.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// Value of the `Retry-After` header.
+    pub retry_after: Option<Duration>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        HttpResponse {
+            status,
+            retry_after: None,
+            body: body.into(),
+        }
+    }
+}
+
+/// Sends requests for the `RestClient`. Implement this to run the client against something other than the Polygon.io api.
+pub trait Transport: Send + Sync {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse, PolygonError>>;
+}
+
+/// The default transport, sending requests with reqwest.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(timeout: Option<Duration>) -> Result<Self, PolygonError> {
+        let mut client = reqwest::ClientBuilder::new();
+
+        if let Some(timeout) = timeout {
+            client = client.timeout(timeout);
+        }
+
+        Ok(ReqwestTransport {
+            client: client.build().map_err(|e| PolygonError::Config(e.to_string()))?,
+        })
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse, PolygonError>> {
+        Box::pin(async move {
+            let res = self
+                .client
+                .get(request.url())
+                .bearer_auth(&request.auth_key)
+                .query(&request.query)
+                .send()
+                .await?;
+
+            let status = res.status().as_u16();
+            let retry_after = retry_after(res.headers());
+            let body = res.text().await?;
+
+            Ok(HttpResponse { status, retry_after, body })
+        })
+    }
+}
+
+/// Reads the `Retry-After` header, which Polygon.io sends as a number of seconds.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// In-memory transport answering requests with canned responses, matched by path and query.
+/// Several responses for the same request are returned in order, the last one for all following requests.
+/// Requests without a response get a 404. Clones share their responses and the log of sent requests.
+#[derive(Debug, Default, Clone)]
+pub struct MockTransport {
+    responses: Arc<Mutex<HashMap<String, VecDeque<HttpResponse>>>>,
+    sent: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        MockTransport::default()
+    }
+
+    /// Answer requests for `path` with exactly the `query` parameters with the json `body`.
+    pub fn with_response(self, path: &str, query: &[(&str, &str)], body: impl Into<String>) -> MockTransport {
+        self.with_status(path, query, 200, body)
+    }
+
+    /// Answer requests for `path` with exactly the `query` parameters with `status` and `body`.
+    pub fn with_status(self, path: &str, query: &[(&str, &str)], status: u16, body: impl Into<String>) -> MockTransport {
+        self.with_http_response(path, query, HttpResponse::new(status, body))
+    }
+
+    /// Answer requests for `path` with exactly the `query` parameters with `response`, e.g. to set `retry_after`.
+    pub fn with_http_response(self, path: &str, query: &[(&str, &str)], response: HttpResponse) -> MockTransport {
+        let key = request_key(path, query.iter().copied());
+        self.responses.lock().unwrap().entry(key).or_default().push_back(response);
+        self
+    }
+
+    /// Keys of the requests sent so far, in the order they were sent.
+    pub fn sent_requests(&self) -> Vec<String> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse, PolygonError>> {
+        let key = request.key();
+        self.sent.lock().unwrap().push(key.clone());
+
+        let mut responses = self.responses.lock().unwrap();
+        let response = match responses.get_mut(&key) {
+            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+            Some(queue) => queue[0].clone(),
+            None => {
+                let body = serde_json::json!({ "status": "NOT_FOUND", "message": format!("no mock response for {}", key) });
+                HttpResponse::new(404, body.to_string())
+            }
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
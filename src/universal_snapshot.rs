@@ -0,0 +1,447 @@
+pub use crate::common::AssetClass;
+use crate::common::{Money, Order};
+use crate::filter::RangeFilter;
+use crate::pagination::Paginated;
+use crate::rest_client::RequestType;
+use crate::utils::{date_format, money_format, nanos_format};
+use crate::validation::{self, ValidationError};
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use time::{Date, OffsetDateTime};
+
+/// /v3/snapshot
+/// Get snapshots for assets of all types: stocks, options, indices, fx and crypto.
+const UNIVERSAL_SNAPSHOT_PATH: &str = "/v3/snapshot";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UniversalSnapshotResponse {
+    /// If present, this value can be used to fetch the next page of data.
+    #[serde(default = "String::default")]
+    pub next_url: String,
+    /// A request id assigned by the server.
+    #[serde(default = "String::default")]
+    pub request_id: String,
+    /// One snapshot per ticker, in the order of the request.
+    #[serde(default = "Vec::default")]
+    pub results: Vec<UniversalSnapshot>,
+    /// The status of this request's response.
+    #[serde(default = "String::default")]
+    pub status: String,
+}
+
+impl Paginated for UniversalSnapshotResponse {
+    type Item = UniversalSnapshot;
+
+    fn next_url(&self) -> Option<&str> {
+        Some(self.next_url.as_str()).filter(|next_url| !next_url.is_empty())
+    }
+
+    fn into_results(self) -> Vec<UniversalSnapshot> {
+        self.results
+    }
+}
+
+/// The snapshot of one ticker, decoded by the `type` of the asset.
+/// Tickers which could not be found are returned as `Error`. Asset types added after this client was written are returned as `Unknown`,
+/// and snapshots which don't match the model of their type as `Malformed` with the decoding error, so they don't fail the whole page.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UniversalSnapshot {
+    Stocks(AssetSnapshot),
+    Options(Box<OptionSnapshot>),
+    Indices(IndexSnapshot),
+    FX(AssetSnapshot),
+    Crypto(AssetSnapshot),
+    Error(SnapshotError),
+    Unknown(serde_json::Value),
+    Malformed { value: serde_json::Value, error: String },
+}
+
+impl UniversalSnapshot {
+    /// The ticker of the snapshot, if the api returned one.
+    pub fn ticker(&self) -> Option<&str> {
+        match self {
+            UniversalSnapshot::Stocks(snapshot) | UniversalSnapshot::FX(snapshot) | UniversalSnapshot::Crypto(snapshot) => Some(&snapshot.ticker),
+            UniversalSnapshot::Options(snapshot) => Some(&snapshot.ticker),
+            UniversalSnapshot::Indices(snapshot) => Some(&snapshot.ticker),
+            UniversalSnapshot::Error(error) => Some(&error.ticker),
+            UniversalSnapshot::Unknown(value) | UniversalSnapshot::Malformed { value, .. } => value.get("ticker").and_then(|ticker| ticker.as_str()),
+        }
+    }
+}
+
+/// The snapshots of known asset types, tagged by their `type` field.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum TaggedSnapshot {
+    Stocks(AssetSnapshot),
+    Options(Box<OptionSnapshot>),
+    Indices(IndexSnapshot),
+    FX(AssetSnapshot),
+    Crypto(AssetSnapshot),
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum TaggedSnapshotRef<'a> {
+    Stocks(&'a AssetSnapshot),
+    Options(&'a OptionSnapshot),
+    Indices(&'a IndexSnapshot),
+    FX(&'a AssetSnapshot),
+    Crypto(&'a AssetSnapshot),
+}
+
+impl<'de> Deserialize<'de> for UniversalSnapshot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        if value.get("error").is_some() {
+            return Ok(match SnapshotError::deserialize(&value) {
+                Ok(error) => UniversalSnapshot::Error(error),
+                Err(error) => UniversalSnapshot::Malformed {
+                    value,
+                    error: error.to_string(),
+                },
+            });
+        }
+
+        match value.get("type").and_then(|r#type| r#type.as_str()) {
+            Some("stocks" | "options" | "indices" | "fx" | "crypto") => Ok(match TaggedSnapshot::deserialize(&value) {
+                Ok(TaggedSnapshot::Stocks(snapshot)) => UniversalSnapshot::Stocks(snapshot),
+                Ok(TaggedSnapshot::Options(snapshot)) => UniversalSnapshot::Options(snapshot),
+                Ok(TaggedSnapshot::Indices(snapshot)) => UniversalSnapshot::Indices(snapshot),
+                Ok(TaggedSnapshot::FX(snapshot)) => UniversalSnapshot::FX(snapshot),
+                Ok(TaggedSnapshot::Crypto(snapshot)) => UniversalSnapshot::Crypto(snapshot),
+                Err(error) => UniversalSnapshot::Malformed {
+                    value,
+                    error: error.to_string(),
+                },
+            }),
+            _ => Ok(UniversalSnapshot::Unknown(value)),
+        }
+    }
+}
+
+impl Serialize for UniversalSnapshot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            UniversalSnapshot::Stocks(snapshot) => TaggedSnapshotRef::Stocks(snapshot).serialize(serializer),
+            UniversalSnapshot::Options(snapshot) => TaggedSnapshotRef::Options(snapshot).serialize(serializer),
+            UniversalSnapshot::Indices(snapshot) => TaggedSnapshotRef::Indices(snapshot).serialize(serializer),
+            UniversalSnapshot::FX(snapshot) => TaggedSnapshotRef::FX(snapshot).serialize(serializer),
+            UniversalSnapshot::Crypto(snapshot) => TaggedSnapshotRef::Crypto(snapshot).serialize(serializer),
+            UniversalSnapshot::Error(error) => error.serialize(serializer),
+            UniversalSnapshot::Unknown(value) | UniversalSnapshot::Malformed { value, .. } => value.serialize(serializer),
+        }
+    }
+}
+
+/// The snapshot of a stock, fx pair or crypto pair.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AssetSnapshot {
+    /// The exchange symbol that this item is traded under.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// The name of the asset.
+    #[serde(default = "String::default")]
+    pub name: String,
+    /// The market status of the asset, e.g. `open`, `closed`, `early_trading` or `late_trading`.
+    #[serde(default = "String::default")]
+    pub market_status: String,
+    /// Fair market value, only available on Business plans.
+    #[serde(default, with = "money_format::option")]
+    pub fmv: Option<Money>,
+    /// The prices and volume of the current or most recent trading session.
+    pub session: Option<Session>,
+    /// The most recent trade. Not set for fx.
+    pub last_trade: Option<SnapshotTrade>,
+    /// The most recent quote.
+    pub last_quote: Option<SnapshotQuote>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OptionSnapshot {
+    /// The options ticker, e.g. `O:AAPL230616C00150000`.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// The name of the contract.
+    #[serde(default = "String::default")]
+    pub name: String,
+    /// The market status of the contract.
+    #[serde(default = "String::default")]
+    pub market_status: String,
+    /// Fair market value, only available on Business plans.
+    #[serde(default, with = "money_format::option")]
+    pub fmv: Option<Money>,
+    /// The price of the underlying asset for the contract to break even.
+    #[serde(default, with = "money_format::option")]
+    pub break_even_price: Option<Money>,
+    pub details: Option<OptionDetails>,
+    pub greeks: Option<Greeks>,
+    /// The market's forecast for the volatility of the underlying asset, based on this option's current price.
+    pub implied_volatility: Option<f64>,
+    /// The quantity of this contract held at the end of the last trading day.
+    pub open_interest: Option<f64>,
+    pub underlying_asset: Option<UnderlyingAsset>,
+    /// The prices and volume of the current or most recent trading session.
+    pub session: Option<Session>,
+    /// The most recent trade.
+    pub last_trade: Option<SnapshotTrade>,
+    /// The most recent quote.
+    pub last_quote: Option<SnapshotQuote>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OptionDetails {
+    /// The type of the contract, `call` or `put`.
+    #[serde(default = "String::default")]
+    pub contract_type: String,
+    /// The exercise style of the contract, e.g. `american` or `european`.
+    #[serde(default = "String::default")]
+    pub exercise_style: String,
+    #[serde(default, with = "date_format::option")]
+    pub expiration_date: Option<Date>,
+    /// The number of shares per contract.
+    pub shares_per_contract: Option<u32>,
+    #[serde(default, with = "money_format::option")]
+    pub strike_price: Option<Money>,
+}
+
+/// The greeks of an option contract, calculated using the Black-Scholes model.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Greeks {
+    #[serde(default = "f64::default")]
+    pub delta: f64,
+    #[serde(default = "f64::default")]
+    pub gamma: f64,
+    #[serde(default = "f64::default")]
+    pub theta: f64,
+    #[serde(default = "f64::default")]
+    pub vega: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UnderlyingAsset {
+    /// The ticker of the underlying asset.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// The price of the underlying stock.
+    #[serde(default, with = "money_format::option")]
+    pub price: Option<Money>,
+    /// The value of the underlying index.
+    pub value: Option<f64>,
+    /// The change in price of the underlying asset for the contract to break even.
+    #[serde(default, with = "money_format::option")]
+    pub change_to_break_even: Option<Money>,
+    #[serde(default, with = "nanos_format::option")]
+    pub last_updated: Option<OffsetDateTime>,
+    /// `REAL-TIME` or `DELAYED`.
+    #[serde(default = "String::default")]
+    pub timeframe: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IndexSnapshot {
+    /// The index ticker, e.g. `I:SPX`.
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// The name of the index.
+    #[serde(default = "String::default")]
+    pub name: String,
+    /// The market status of the index.
+    #[serde(default = "String::default")]
+    pub market_status: String,
+    /// The value of the index.
+    pub value: Option<f64>,
+    /// The values of the current or most recent trading session.
+    pub session: Option<Session>,
+    #[serde(default, with = "nanos_format::option")]
+    pub last_updated: Option<OffsetDateTime>,
+    /// `REAL-TIME` or `DELAYED`.
+    #[serde(default = "String::default")]
+    pub timeframe: String,
+}
+
+/// A ticker of the request which has no snapshot.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SnapshotError {
+    #[serde(default = "String::default")]
+    pub ticker: String,
+    /// The error code, e.g. `NOT_FOUND` or `NOT_ENTITLED`.
+    #[serde(default = "String::default")]
+    pub error: String,
+    #[serde(default = "String::default")]
+    pub message: String,
+}
+
+/// The prices and volume of a trading session. Fields which don't apply to the asset type are not set.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Session {
+    /// The value of the change from the previous close.
+    #[serde(default, with = "money_format::option")]
+    pub change: Option<Money>,
+    /// The percentage change from the previous close.
+    pub change_percent: Option<f64>,
+    #[serde(default, with = "money_format::option")]
+    pub early_trading_change: Option<Money>,
+    pub early_trading_change_percent: Option<f64>,
+    #[serde(default, with = "money_format::option")]
+    pub regular_trading_change: Option<Money>,
+    pub regular_trading_change_percent: Option<f64>,
+    #[serde(default, with = "money_format::option")]
+    pub late_trading_change: Option<Money>,
+    pub late_trading_change_percent: Option<f64>,
+    #[serde(default, with = "money_format::option")]
+    pub open: Option<Money>,
+    #[serde(default, with = "money_format::option")]
+    pub high: Option<Money>,
+    #[serde(default, with = "money_format::option")]
+    pub low: Option<Money>,
+    #[serde(default, with = "money_format::option")]
+    pub close: Option<Money>,
+    #[serde(default, with = "money_format::option")]
+    pub previous_close: Option<Money>,
+    /// The most recent price.
+    #[serde(default, with = "money_format::option")]
+    pub price: Option<Money>,
+    /// The trading volume of the session.
+    pub volume: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SnapshotTrade {
+    /// The trade conditions, see the Conditions endpoint for their meaning.
+    #[serde(default = "Vec::default")]
+    pub conditions: Vec<u32>,
+    /// The id of the exchange the trade happened on, see the Exchanges endpoint for their names.
+    #[serde(default = "u32::default")]
+    pub exchange: u32,
+    #[serde(default = "String::default")]
+    pub id: String,
+    #[serde(with = "money_format")]
+    pub price: Money,
+    #[serde(default = "f64::default")]
+    pub size: f64,
+    #[serde(default, with = "nanos_format::option")]
+    pub last_updated: Option<OffsetDateTime>,
+    /// `REAL-TIME` or `DELAYED`.
+    #[serde(default = "String::default")]
+    pub timeframe: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SnapshotQuote {
+    /// The ask price. Not set when there is no ask.
+    #[serde(default, with = "money_format::option")]
+    pub ask: Option<Money>,
+    #[serde(default = "f64::default")]
+    pub ask_size: f64,
+    pub ask_exchange: Option<u32>,
+    /// The bid price. Not set when there is no bid.
+    #[serde(default, with = "money_format::option")]
+    pub bid: Option<Money>,
+    #[serde(default = "f64::default")]
+    pub bid_size: f64,
+    pub bid_exchange: Option<u32>,
+    /// The exchange of the quote, for fx.
+    pub exchange: Option<u32>,
+    #[serde(default, with = "money_format::option")]
+    pub midpoint: Option<Money>,
+    #[serde(default, with = "nanos_format::option")]
+    pub last_updated: Option<OffsetDateTime>,
+    /// `REAL-TIME` or `DELAYED`.
+    #[serde(default = "String::default")]
+    pub timeframe: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum UniversalSnapshotSort {
+    Ticker,
+}
+
+impl fmt::Display for UniversalSnapshotSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", &self).to_lowercase())
+    }
+}
+
+#[derive(Debug)]
+pub struct UniversalSnapshotRequest {
+    pub path: &'static str,
+    pub parameters: HashMap<String, String>,
+}
+
+impl UniversalSnapshotRequest {
+    pub fn new() -> Self {
+        UniversalSnapshotRequest {
+            path: UNIVERSAL_SNAPSHOT_PATH,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Filter by ticker symbol, either an exact symbol or a range. Can't be combined with [tickers](Self::tickers).
+    pub fn ticker(mut self, ticker: impl Into<RangeFilter<String>>) -> UniversalSnapshotRequest {
+        ticker.into().apply("ticker", &mut self.parameters);
+        self
+    }
+
+    /// Get snapshots of a list of up to 250 tickers of any asset type, e.g. `["AAPL", "O:AAPL230616C00150000", "X:BTCUSD"]`.
+    pub fn tickers<T: AsRef<str>>(mut self, tickers: impl IntoIterator<Item = T>) -> UniversalSnapshotRequest {
+        let tickers: Vec<String> = tickers.into_iter().map(|ticker| ticker.as_ref().to_string()).collect();
+        self.parameters.insert("ticker.any_of".to_string(), tickers.join(","));
+        self
+    }
+
+    /// Only return snapshots of this asset class.
+    pub fn r#type(mut self, asset_class: AssetClass) -> UniversalSnapshotRequest {
+        self.parameters.insert("type".to_string(), asset_class.to_string());
+        self
+    }
+
+    /// Order results based on the sort field.
+    pub fn order(mut self, order: Order) -> UniversalSnapshotRequest {
+        self.parameters.insert("order".to_string(), order.to_string());
+        self
+    }
+
+    /// Limit the number of results returned, default is 10 and max is 250.
+    pub fn limit(mut self, limit: u32) -> UniversalSnapshotRequest {
+        self.parameters.insert("limit".to_string(), limit.to_string());
+        self
+    }
+
+    /// Sort field used for ordering.
+    pub fn sort(mut self, sort: UniversalSnapshotSort) -> UniversalSnapshotRequest {
+        self.parameters.insert("sort".to_string(), sort.to_string());
+        self
+    }
+}
+
+impl Default for UniversalSnapshotRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestType for UniversalSnapshotRequest {
+    fn get_url(&self) -> String {
+        self.path.to_string()
+    }
+
+    fn get_query(&self) -> Vec<(&String, &String)> {
+        self.parameters.iter().collect()
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::limit(&self.parameters, 250)?;
+        validation::range::<String>(&self.parameters, "ticker")?;
+        validation::exclusive(
+            &self.parameters,
+            "ticker.any_of",
+            &["ticker", "ticker.gt", "ticker.gte", "ticker.lt", "ticker.lte"],
+        )
+    }
+}
@@ -6,7 +6,19 @@
 //     format!("{}", date.format(&format).unwrap())
 // }
 
+use serde::Deserialize;
 use std::collections::HashMap;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+// Serde format for dates sent as `YYYY-MM-DD`, use with `#[serde(with = "date_format")]`
+// or `#[serde(with = "date_format::option")]`.
+time::serde::format_description!(pub date_format, Date, "[year]-[month]-[day]");
+
+/// Formats a timestamp as RFC 3339, the format used by query parameters like `published_utc`.
+pub fn timestamp_to_string(timestamp: &OffsetDateTime) -> String {
+    timestamp.format(&Rfc3339).unwrap_or_else(|_| timestamp.to_string())
+}
 
 pub fn params_to_query_string(params: &HashMap<String, String>) -> String {
     let mut query = String::new();
@@ -20,3 +32,177 @@ pub fn params_to_query_string(params: &HashMap<String, String>) -> String {
     }
     query
 }
+
+/// A number which the api may send either as a json number or as a string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(serde_json::Number),
+    String(String),
+}
+
+impl NumberOrString {
+    fn into_string(self) -> String {
+        match self {
+            NumberOrString::Number(number) => number.to_string(),
+            NumberOrString::String(string) => string.trim().to_string(),
+        }
+    }
+}
+
+/// Serde format for `Money` values sent as floats or strings, use with `#[serde(with = "money_format")]`.
+/// Decimals are parsed from the shortest representation of the number, so `0.24` stays exactly `0.24`.
+pub mod money_format {
+    use super::NumberOrString;
+    use crate::common::Money;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    #[cfg(feature = "decimal")]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        use std::str::FromStr;
+        let value = NumberOrString::deserialize(deserializer)?.into_string();
+        Money::from_str(&value)
+            .or_else(|_| Money::from_scientific(&value))
+            .map_err(|e| de::Error::custom(format!("invalid amount '{}': {}", value, e)))
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        let value = NumberOrString::deserialize(deserializer)?.into_string();
+        value
+            .parse::<Money>()
+            .map_err(|e| de::Error::custom(format!("invalid amount '{}': {}", value, e)))
+    }
+
+    #[cfg(feature = "decimal")]
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(money)
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(*money)
+    }
+
+    /// Same as `money_format` for optional values, use with `#[serde(default, with = "money_format::option")]`.
+    pub mod option {
+        use crate::common::Money;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        #[derive(Deserialize)]
+        struct Amount(#[serde(with = "super")] Money);
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Money>, D::Error> {
+            Ok(Option::<Amount>::deserialize(deserializer)?.map(|Amount(money)| money))
+        }
+
+        pub fn serialize<S: Serializer>(money: &Option<Money>, serializer: S) -> Result<S::Ok, S::Error> {
+            match money {
+                Some(money) => super::serialize(money, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+/// Serde format for counts like shares outstanding sent as integers, floats or strings,
+/// use with `#[serde(with = "count_format")]`.
+pub mod count_format {
+    use super::NumberOrString;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let value = NumberOrString::deserialize(deserializer)?.into_string();
+        match value.parse::<u64>() {
+            Ok(count) => Ok(count),
+            Err(_) => match value.parse::<f64>() {
+                Ok(count) if count >= 0.0 && count <= u64::MAX as f64 => Ok(count.round() as u64),
+                _ => Err(de::Error::custom(format!("invalid count '{}'", value))),
+            },
+        }
+    }
+
+    pub fn serialize<S: Serializer>(count: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*count)
+    }
+}
+
+/// Serde format for timestamps sent as unix milliseconds, like the start of an aggregate bar,
+/// use with `#[serde(with = "millis_format")]` or `#[serde(default, with = "millis_format::option")]`.
+pub mod millis_format {
+    use super::NumberOrString;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use time::OffsetDateTime;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+        let value = NumberOrString::deserialize(deserializer)?.into_string();
+        value
+            .parse::<i64>()
+            .ok()
+            .and_then(|millis| OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000).ok())
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp '{}'", value)))
+    }
+
+    pub fn serialize<S: Serializer>(timestamp: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64((timestamp.unix_timestamp_nanos() / 1_000_000) as i64)
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use time::OffsetDateTime;
+
+        #[derive(Deserialize)]
+        struct Timestamp(#[serde(with = "super")] OffsetDateTime);
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error> {
+            Ok(Option::<Timestamp>::deserialize(deserializer)?.map(|Timestamp(timestamp)| timestamp))
+        }
+
+        pub fn serialize<S: Serializer>(timestamp: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error> {
+            match timestamp {
+                Some(timestamp) => super::serialize(timestamp, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+/// Serde format for timestamps sent as unix nanoseconds, like the SIP timestamp of a trade,
+/// use with `#[serde(with = "nanos_format")]` or `#[serde(default, with = "nanos_format::option")]`.
+pub mod nanos_format {
+    use super::NumberOrString;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use time::OffsetDateTime;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+        let value = NumberOrString::deserialize(deserializer)?.into_string();
+        value
+            .parse::<i128>()
+            .ok()
+            .and_then(|nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos).ok())
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp '{}'", value)))
+    }
+
+    pub fn serialize<S: Serializer>(timestamp: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(timestamp.unix_timestamp_nanos() as i64)
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use time::OffsetDateTime;
+
+        #[derive(Deserialize)]
+        struct Timestamp(#[serde(with = "super")] OffsetDateTime);
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error> {
+            Ok(Option::<Timestamp>::deserialize(deserializer)?.map(|Timestamp(timestamp)| timestamp))
+        }
+
+        pub fn serialize<S: Serializer>(timestamp: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error> {
+            match timestamp {
+                Some(timestamp) => super::serialize(timestamp, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::filter::FilterValue;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// A request which would be rejected by the api, detected before it is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A parameter of the path, like the ticker of `/v3/reference/tickers/{ticker}`, is not set.
+    MissingPathParameter(&'static str),
+    /// The limit is zero or larger than the maximum of the endpoint.
+    InvalidLimit { limit: String, max: u32 },
+    /// The lower bound of a range filter is above its upper bound.
+    InvertedRange { field: String, lower: String, upper: String },
+    /// Two parameters which can't be used together are both set.
+    MutuallyExclusive(String, String),
+    /// A parameter value could not be parsed as the type of its field.
+    InvalidValue { field: String, value: String },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::MissingPathParameter(name) => write!(f, "path parameter '{}' is not set", name),
+            ValidationError::InvalidLimit { limit, max } => write!(f, "limit {} is not between 1 and {}", limit, max),
+            ValidationError::InvertedRange { field, lower, upper } => {
+                write!(f, "range of '{}' is empty, lower bound {} is above upper bound {}", field, lower, upper)
+            }
+            ValidationError::MutuallyExclusive(a, b) => write!(f, "'{}' and '{}' can't be used together", a, b),
+            ValidationError::InvalidValue { field, value } => write!(f, "invalid value '{}' for '{}'", value, field),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Checks that a path parameter is set.
+pub(crate) fn required(name: &'static str, value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(ValidationError::MissingPathParameter(name));
+    }
+    Ok(())
+}
+
+/// Checks that the `limit` parameter, if set, is between 1 and `max`.
+pub(crate) fn limit(parameters: &HashMap<String, String>, max: u32) -> Result<(), ValidationError> {
+    match parameters.get("limit") {
+        Some(limit) => match limit.parse::<u32>() {
+            Ok(value) if (1..=max).contains(&value) => Ok(()),
+            _ => Err(ValidationError::InvalidLimit { limit: limit.clone(), max }),
+        },
+        None => Ok(()),
+    }
+}
+
+/// Checks that `parameter` isn't combined with any of the `others`.
+pub(crate) fn exclusive(parameters: &HashMap<String, String>, parameter: &str, others: &[&str]) -> Result<(), ValidationError> {
+    if !parameters.contains_key(parameter) {
+        return Ok(());
+    }
+    match others.iter().find(|other| parameters.contains_key(**other)) {
+        Some(other) => Err(ValidationError::MutuallyExclusive(other.to_string(), parameter.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Checks the parameters of a `RangeFilter` on `field`: values parse as `T`, an exact value isn't combined with bounds,
+/// each side has one bound, and the range isn't empty.
+pub(crate) fn range<T: FilterValue + PartialOrd>(parameters: &HashMap<String, String>, field: &str) -> Result<(), ValidationError> {
+    let parse = |suffix: &str| -> Result<Option<(String, T)>, ValidationError> {
+        let name = format!("{}{}", field, suffix);
+        match parameters.get(&name) {
+            Some(value) => match T::from_query_value(value) {
+                Some(parsed) => Ok(Some((name, parsed))),
+                None => Err(ValidationError::InvalidValue {
+                    field: name,
+                    value: value.clone(),
+                }),
+            },
+            None => Ok(None),
+        }
+    };
+
+    let equals = parse("")?;
+    let gt = parse(".gt")?;
+    let gte = parse(".gte")?;
+    let lt = parse(".lt")?;
+    let lte = parse(".lte")?;
+
+    let exclusive_pairs = [(&equals, &gt), (&equals, &gte), (&equals, &lt), (&equals, &lte), (&gt, &gte), (&lt, &lte)];
+    for pair in exclusive_pairs {
+        if let (Some((a, _)), Some((b, _))) = pair {
+            return Err(ValidationError::MutuallyExclusive(a.clone(), b.clone()));
+        }
+    }
+
+    let lower = gt.map(|bound| (bound, false)).or(gte.map(|bound| (bound, true)));
+    let upper = lt.map(|bound| (bound, false)).or(lte.map(|bound| (bound, true)));
+
+    if let (Some(((_, lower), lower_inclusive)), Some(((_, upper), upper_inclusive))) = (&lower, &upper) {
+        let empty = if *lower_inclusive && *upper_inclusive {
+            lower > upper
+        } else {
+            lower >= upper
+        };
+
+        if empty {
+            return Err(ValidationError::InvertedRange {
+                field: field.to_string(),
+                lower: lower.to_query_value(),
+                upper: upper.to_query_value(),
+            });
+        }
+    }
+
+    Ok(())
+}
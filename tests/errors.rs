@@ -0,0 +1,129 @@
+use polygon_io_client_rust::dividends::{DividendRequest, DividendsResponse};
+use polygon_io_client_rust::error::PolygonError;
+use polygon_io_client_rust::polygon_client::PolygonClientBuilder;
+use polygon_io_client_rust::transport::{HttpResponse, MockTransport};
+use std::time::Duration;
+
+const PATH: &str = "/v3/reference/dividends";
+
+async fn send(response: HttpResponse) -> Result<DividendsResponse, PolygonError> {
+    let transport = MockTransport::new().with_http_response(PATH, &[], response);
+    let client = PolygonClientBuilder::new().auth_key("key").transport(transport).build().unwrap();
+    client.get_dividends(&DividendRequest::new()).await
+}
+
+async fn error(status: u16, body: &str) -> PolygonError {
+    send(HttpResponse::new(status, body)).await.unwrap_err()
+}
+
+#[tokio::test]
+async fn unauthorized_statuses() {
+    let body = r#"{"status":"NOT_AUTHORIZED","request_id":"1","message":"Unknown API Key"}"#;
+
+    for status in [401, 403] {
+        match error(status, body).await {
+            PolygonError::Unauthorized { status: actual, message } => {
+                assert_eq!(actual, status);
+                assert_eq!(message, "Unknown API Key");
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+}
+
+#[tokio::test]
+async fn not_found() {
+    let e = error(404, r#"{"status":"NOT_FOUND","message":"Ticker not found."}"#).await;
+    assert!(matches!(&e, PolygonError::NotFound { message } if message == "Ticker not found."));
+    assert_eq!(e.status(), Some(404));
+}
+
+#[tokio::test]
+async fn rate_limited_keeps_retry_after() {
+    let mut response = HttpResponse::new(429, r#"{"status":"ERROR","error":"exceeded the maximum requests per minute"}"#);
+    response.retry_after = Some(Duration::from_secs(12));
+
+    let e = send(response).await.unwrap_err();
+    assert!(matches!(e, PolygonError::RateLimited { retry_after: Some(retry_after) } if retry_after == Duration::from_secs(12)));
+}
+
+#[tokio::test]
+async fn other_statuses_prefer_the_error_field() {
+    let e = error(500, r#"{"error":"internal error","message":"try again"}"#).await;
+    assert!(matches!(&e, PolygonError::Http { status: 500, message } if message == "internal error"));
+}
+
+#[tokio::test]
+async fn error_status_in_a_failed_response() {
+    let body = r#"{"status":"ERROR","request_id":"abc","error":"Could not parse the date."}"#;
+
+    match error(400, body).await {
+        PolygonError::Api {
+            http_status,
+            status,
+            error,
+            request_id,
+            ..
+        } => {
+            assert_eq!(http_status, 400);
+            assert_eq!(status, "ERROR");
+            assert_eq!(error, "Could not parse the date.");
+            assert_eq!(request_id, "abc");
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+
+    let e = error(404, body).await;
+    assert!(matches!(&e, PolygonError::NotFound { message } if message == "Could not parse the date."));
+}
+
+#[tokio::test]
+async fn error_status_in_a_successful_response() {
+    let body = r#"{"status":"ERROR","request_id":"abc","error":"bad_request","message":"Invalid date"}"#;
+
+    match error(200, body).await {
+        PolygonError::Api {
+            http_status,
+            status,
+            error,
+            message,
+            request_id,
+        } => {
+            assert_eq!(http_status, 200);
+            assert_eq!(status, "ERROR");
+            assert_eq!(error, "bad_request");
+            assert_eq!(message, "Invalid date");
+            assert_eq!(request_id, "abc");
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+
+    let e = error(200, r#"{"status":"NOT_AUTHORIZED","message":"not entitled"}"#).await;
+    assert!(matches!(&e, PolygonError::Api { status, .. } if status == "NOT_AUTHORIZED"));
+}
+
+#[tokio::test]
+async fn deserialize_error_keeps_a_snippet_of_the_body() {
+    let body = format!(r#"{{"status":"OK","results":"{}"}}"#, "x".repeat(1000));
+
+    match error(200, &body).await {
+        PolygonError::Deserialize { body: snippet, .. } => {
+            assert_eq!(snippet.chars().count(), 512);
+            assert!(body.starts_with(&snippet));
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn retryable_errors() {
+    assert!(error(429, "").await.is_retryable());
+    for status in [502, 503, 504] {
+        assert!(error(status, "").await.is_retryable(), "{} should be retryable", status);
+    }
+    for status in [400, 401, 403, 404, 500] {
+        assert!(!error(status, "").await.is_retryable(), "{} should not be retryable", status);
+    }
+    assert!(!error(200, r#"{"status":"ERROR"}"#).await.is_retryable());
+    assert!(error(503, r#"{"status":"ERROR"}"#).await.is_retryable());
+}
@@ -0,0 +1,46 @@
+use polygon_io_client_rust::common::Money;
+use polygon_io_client_rust::dividends::DividendRequest;
+use polygon_io_client_rust::filter::RangeFilter;
+use polygon_io_client_rust::ticker_news::TickerNewsRequest;
+use polygon_io_client_rust::tickers::TickersRequest;
+use std::collections::HashMap;
+use time::macros::{date, datetime};
+
+fn query(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+}
+
+#[test]
+fn exact_values_and_ranges_render_suffixes() {
+    let request = DividendRequest::new()
+        .ticker("AAPL")
+        .cash_amount(RangeFilter::between(Money::from(1), Money::from(2)))
+        .ex_dividend_date(..=date!(2023 - 06 - 30))
+        .pay_date(date!(2023 - 01 - 01)..date!(2023 - 02 - 01));
+
+    assert_eq!(
+        request.parameters,
+        query(&[
+            ("ticker", "AAPL"),
+            ("cash_amount.gte", "1"),
+            ("cash_amount.lte", "2"),
+            ("ex_dividend_date.lte", "2023-06-30"),
+            ("pay_date.gte", "2023-01-01"),
+            ("pay_date.lt", "2023-02-01"),
+        ])
+    );
+}
+
+#[test]
+fn setting_a_filter_again_replaces_it() {
+    let request = TickersRequest::new().ticker("A".."B").ticker(RangeFilter::gt("C"));
+
+    assert_eq!(request.parameters, query(&[("active", "true"), ("ticker.gt", "C")]));
+}
+
+#[test]
+fn timestamps_render_as_rfc3339() {
+    let request = TickerNewsRequest::new().published_utc(datetime!(2021-04-26 02:33:17 UTC)..);
+
+    assert_eq!(request.parameters, query(&[("published_utc.gte", "2021-04-26T02:33:17Z")]));
+}
@@ -0,0 +1,575 @@
+use futures::TryStreamExt;
+use polygon_io_client_rust::aggregates::{AggregatesRequest, Timespan};
+use polygon_io_client_rust::common::{AssetClass, Order, Tape};
+use polygon_io_client_rust::conditions::{ConditionsRequest, DataType};
+use polygon_io_client_rust::daily_open_close::DailyOpenCloseRequest;
+use polygon_io_client_rust::dividends::{DividendRequest, DividendType, DividendsResponse};
+use polygon_io_client_rust::error::PolygonError;
+use polygon_io_client_rust::filter::NanoTimestamp;
+use polygon_io_client_rust::financials::{FinancialsRequest, FinancialsTimeframe};
+use polygon_io_client_rust::grouped_daily::{GroupedDailyRequest, GroupedDailyResponse};
+use polygon_io_client_rust::last_quote::{ForexLastQuoteRequest, LastQuoteRequest};
+use polygon_io_client_rust::last_trade::{CryptoLastTradeRequest, LastTradeRequest};
+use polygon_io_client_rust::market_status::{HolidayStatus, MarketHolidaysRequest, MarketState, MarketStatusRequest};
+use polygon_io_client_rust::pagination::{Paginated, PaginationOptions};
+use polygon_io_client_rust::polygon_client::{PolygonClient, PolygonClientBuilder};
+use polygon_io_client_rust::previous_close::PreviousCloseRequest;
+use polygon_io_client_rust::quotes::QuotesRequest;
+use polygon_io_client_rust::snapshots::{Direction, SnapshotGainersLosersRequest, SnapshotTickerRequest, SnapshotTickersRequest};
+use polygon_io_client_rust::splits::{SplitRequest, SplitSort};
+use polygon_io_client_rust::ticker_details::TickerDetailsRequest;
+use polygon_io_client_rust::ticker_events::{TickerChange, TickerEventData, TickerEventType, TickerEventsRequest};
+use polygon_io_client_rust::ticker_news::TickerNewsRequest;
+use polygon_io_client_rust::tickers::{Locale, Market, TickerType, TickersRequest};
+use polygon_io_client_rust::trades::{TradesRequest, TradesSort};
+use polygon_io_client_rust::transport::MockTransport;
+use polygon_io_client_rust::universal_snapshot::{UniversalSnapshot, UniversalSnapshotRequest, UniversalSnapshotResponse};
+use time::macros::{date, datetime};
+
+fn replay_client() -> PolygonClient {
+    PolygonClientBuilder::new()
+        .replay(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/cassettes"))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn tickers() {
+    let client = replay_client();
+    let request = TickersRequest::new().market(Market::Stocks).limit(1);
+
+    let response = client.get_tickers(&request).await.unwrap();
+    assert!(response.next_url().is_some());
+    assert_eq!(response.count, 1);
+    assert_eq!(response.results[0].ticker, "A");
+    assert_eq!(response.results[0].name, "Agilent Technologies Inc.");
+    assert_eq!(response.results[0].primary_exchange, "XNYS");
+    assert_eq!(response.results[0].market, Market::Stocks);
+    assert_eq!(response.results[0].locale, Locale::US);
+    assert_eq!(response.results[0].r#type, Some(TickerType::CS));
+    assert_eq!(response.results[0].last_updated_utc, Some(datetime!(2021-04-25 00:00:00 UTC)));
+    assert_eq!(response.results[0].delisted_utc, None);
+}
+
+#[tokio::test]
+async fn tickers_stream_follows_next_url() {
+    let client = replay_client();
+    let request = TickersRequest::new().market(Market::Stocks).limit(1);
+
+    let tickers: Vec<_> = client.tickers_stream(&request, PaginationOptions::new()).try_collect().await.unwrap();
+    let symbols: Vec<_> = tickers.iter().map(|ticker| ticker.ticker.as_str()).collect();
+    assert_eq!(symbols, ["A", "AA"]);
+
+    let tickers: Vec<_> = client
+        .tickers_stream(&request, PaginationOptions::new().max_pages(1))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(tickers.len(), 1);
+}
+
+#[tokio::test]
+async fn ticker_details() {
+    let client = replay_client();
+    let request = TickerDetailsRequest::new().ticker("BP");
+
+    let response = client.get_tickers_details(&request).await.unwrap();
+    let details = &response.results;
+    assert_eq!(details.ticker, "BP");
+    assert_eq!(details.sic_code, "2911");
+    assert_eq!(details.r#type, Some(TickerType::ADRC));
+    assert_eq!(details.round_lot, 100);
+    assert_eq!(details.list_date, Some(date!(1977 - 01 - 01)));
+    assert_eq!(details.address.as_ref().unwrap().city, "LONDON");
+}
+
+#[tokio::test]
+async fn ticker_details_large_values() {
+    let client = replay_client();
+    let request = TickerDetailsRequest::new().ticker("AAPL");
+
+    let response = client.get_tickers_details(&request).await.unwrap();
+    let details = &response.results;
+    assert_eq!(details.market_cap.to_string(), "2771126040150.41");
+    assert_eq!(details.weighted_shares_outstanding, 16_334_371_000);
+    assert_eq!(details.share_class_shares_outstanding, 16_406_400_000);
+}
+
+#[tokio::test]
+async fn ticker_events() {
+    let client = replay_client();
+    let request = TickerEventsRequest::new().id("META").types([TickerEventType::TickerChange]);
+
+    let response = client.get_ticker_events(&request).await.unwrap();
+    let results = &response.results;
+    assert_eq!(results.composite_figi, "BBG000MM2P62");
+    assert_eq!(results.events.len(), 3);
+    assert_eq!(results.events[1].date, date!(2012 - 05 - 18));
+    assert_eq!(
+        results.events[1].event,
+        TickerEventData::TickerChange {
+            ticker_change: TickerChange { ticker: "FB".to_string() }
+        }
+    );
+    assert_eq!(results.events[2].event, TickerEventData::Unknown);
+}
+
+#[tokio::test]
+async fn ticker_news() {
+    let client = replay_client();
+    let request = TickerNewsRequest::new().ticker("AAPL").limit(1);
+
+    let response = client.get_ticker_news(&request).await.unwrap();
+    assert_eq!(response.results.len(), 1);
+
+    let article = &response.results[0];
+    assert_eq!(article.publisher.as_ref().unwrap().name, "Benzinga");
+    assert_eq!(article.tickers.len(), 12);
+    assert_eq!(article.keywords[0], "Sector ETFs");
+    assert_eq!(article.published_utc, datetime!(2021-04-26 02:33:17 UTC));
+}
+
+#[tokio::test]
+async fn dividends() {
+    let client = replay_client();
+    let request = DividendRequest::new().ticker("AAPL").limit(2);
+
+    let response = client.get_dividends(&request).await.unwrap();
+    assert!(response.next_url().is_none());
+    assert_eq!(response.results.len(), 2);
+
+    let dividend = &response.results[0];
+    assert_eq!(dividend.dividend_type, DividendType::CD);
+    assert_eq!(dividend.frequency, 4);
+    assert_eq!(dividend.cash_amount.to_string(), "0.24");
+    assert_eq!(dividend.ex_dividend_date, date!(2023 - 08 - 11));
+    assert_eq!(dividend.pay_date, Some(date!(2023 - 08 - 17)));
+}
+
+fn aggregates_request() -> AggregatesRequest {
+    AggregatesRequest::new()
+        .ticker("AAPL")
+        .range(1, Timespan::Day)
+        .from(date!(2023 - 01 - 09))
+        .to(date!(2023 - 01 - 13))
+        .adjusted(true)
+        .sort(Order::Asc)
+        .limit(3)
+}
+
+#[tokio::test]
+async fn aggregates() {
+    let client = replay_client();
+
+    let response = client.get_aggregates(&aggregates_request()).await.unwrap();
+    assert!(response.next_url().is_some());
+    assert_eq!(response.ticker, "AAPL");
+    assert_eq!(response.results_count, 3);
+
+    let bar = &response.results[0];
+    assert_eq!(bar.open.to_string(), "130.465");
+    assert_eq!(bar.high.to_string(), "133.41");
+    assert_eq!(bar.low.to_string(), "129.89");
+    assert_eq!(bar.close.to_string(), "130.15");
+    assert_eq!(bar.volume, 70_790_813.0);
+    assert_eq!(bar.vwap.map(|vwap| vwap.to_string()), Some("131.6292".to_string()));
+    assert_eq!(bar.transactions, Some(645_365));
+    assert_eq!(bar.timestamp, datetime!(2023-01-09 05:00:00 UTC));
+}
+
+#[tokio::test]
+async fn aggregates_stream_follows_next_url() {
+    let client = replay_client();
+    let request = aggregates_request();
+
+    let bars: Vec<_> = client.aggregates_stream(&request, PaginationOptions::new()).try_collect().await.unwrap();
+    let days: Vec<_> = bars.iter().map(|bar| bar.timestamp.date().day()).collect();
+    assert_eq!(days, [9, 10, 11, 12, 13]);
+}
+
+#[tokio::test]
+async fn grouped_daily() {
+    let client = replay_client();
+    let request = GroupedDailyRequest::new()
+        .market(Market::Stocks)
+        .date(date!(2023 - 01 - 09))
+        .adjusted(true);
+
+    let response = client.get_grouped_daily(&request).await.unwrap();
+    let tickers: Vec<_> = response.results.iter().map(|result| result.ticker.as_str()).collect();
+    assert_eq!(tickers, ["TTMI", "AAPL", "SBIG"]);
+
+    let aapl = &response.results[1].bar;
+    assert_eq!(aapl.close.to_string(), "130.15");
+    assert_eq!(aapl.transactions, Some(645_365));
+
+    let sbig = &response.results[2].bar;
+    assert_eq!(sbig.vwap, None);
+    assert_eq!(sbig.transactions, None);
+
+    let json = serde_json::to_string(&response).unwrap();
+    assert_eq!(serde_json::from_str::<GroupedDailyResponse>(&json).unwrap(), response);
+}
+
+#[tokio::test]
+async fn daily_open_close() {
+    let client = replay_client();
+    let request = DailyOpenCloseRequest::new().ticker("AAPL").date(date!(2023 - 01 - 09)).adjusted(true);
+
+    let response = client.get_daily_open_close(&request).await.unwrap();
+    assert_eq!(response.symbol, "AAPL");
+    assert_eq!(response.from, date!(2023 - 01 - 09));
+    assert_eq!(response.open.to_string(), "130.465");
+    assert_eq!(response.close.to_string(), "130.15");
+    assert_eq!(response.pre_market.map(|price| price.to_string()), Some("129.6".to_string()));
+    assert_eq!(response.after_hours.map(|price| price.to_string()), Some("129.85".to_string()));
+}
+
+#[tokio::test]
+async fn previous_close() {
+    let client = replay_client();
+    let request = PreviousCloseRequest::new().ticker("AAPL");
+
+    let response = client.get_previous_close(&request).await.unwrap();
+    let bar = response.bar().unwrap();
+    assert_eq!(bar.ticker, "AAPL");
+    assert_eq!(bar.bar.close.to_string(), "130.15");
+    assert_eq!(bar.bar.timestamp, datetime!(2023-01-09 21:00:00 UTC));
+}
+
+fn trades_request() -> TradesRequest {
+    TradesRequest::new()
+        .ticker("AAPL")
+        .timestamp(NanoTimestamp::from(datetime!(2023-01-09 14:30:00 UTC))..)
+        .order(Order::Asc)
+        .sort(TradesSort::Timestamp)
+        .limit(2)
+}
+
+#[tokio::test]
+async fn trades() {
+    let client = replay_client();
+
+    let response = client.get_trades(&trades_request()).await.unwrap();
+    assert!(response.next_url().is_some());
+
+    let trade = &response.results[0];
+    assert_eq!(trade.conditions, [12, 37]);
+    assert_eq!(trade.exchange, 4);
+    assert_eq!(trade.price.to_string(), "130.465");
+    assert_eq!(trade.size, 18.0);
+    assert_eq!(trade.tape, Some(Tape::C));
+    assert_eq!(trade.trf_id, Some(202));
+    assert_eq!(trade.sip_timestamp, datetime!(2023-01-09 14:30:00.003519232 UTC));
+    assert_eq!(trade.participant_timestamp, Some(datetime!(2023-01-09 14:30:00.003285 UTC)));
+    assert_eq!(response.results[1].trf_timestamp, None);
+}
+
+#[tokio::test]
+async fn trades_stream_follows_next_url() {
+    let client = replay_client();
+    let request = trades_request();
+
+    let trades: Vec<_> = client.trades_stream(&request, PaginationOptions::new()).try_collect().await.unwrap();
+    let sequence_numbers: Vec<_> = trades.iter().map(|trade| trade.sequence_number).collect();
+    assert_eq!(sequence_numbers, [1001, 1002, 1003]);
+}
+
+#[tokio::test]
+async fn quotes() {
+    let client = replay_client();
+    let request = QuotesRequest::new()
+        .ticker("AAPL")
+        .timestamp(NanoTimestamp::from(datetime!(2023-01-09 14:30:00 UTC))..NanoTimestamp::from(datetime!(2023-01-09 14:30:01 UTC)))
+        .limit(2);
+
+    let response = client.get_quotes(&request).await.unwrap();
+    assert!(response.next_url().is_none());
+
+    let quote = &response.results[0];
+    assert_eq!(quote.bid_price.unwrap().to_string(), "130.46");
+    assert_eq!(quote.ask_price.unwrap().to_string(), "130.48");
+    assert_eq!(quote.bid_size, 3.0);
+    assert_eq!(quote.ask_exchange, 12);
+    assert_eq!(quote.indicators, [604]);
+    assert_eq!(quote.sip_timestamp, datetime!(2023-01-09 14:30:00.000553472 UTC));
+    assert_eq!(response.results[1].indicators, Vec::<u32>::new());
+}
+
+#[tokio::test]
+async fn last_trade() {
+    let client = replay_client();
+
+    let response = client.get_last_trade(&LastTradeRequest::new().ticker("AAPL")).await.unwrap();
+    let trade = &response.results;
+    assert_eq!(trade.ticker, "AAPL");
+    assert_eq!(trade.price.to_string(), "126.9");
+    assert_eq!(trade.size, 25.0);
+    assert_eq!(trade.exchange, 4);
+    assert_eq!(trade.trf_id, Some(12));
+    assert_eq!(trade.tape, Some(Tape::C));
+    assert_eq!(trade.sip_timestamp, datetime!(2021-04-08 17:02:22.969834 UTC));
+}
+
+#[tokio::test]
+async fn last_quote() {
+    let client = replay_client();
+
+    let response = client.get_last_quote(&LastQuoteRequest::new().ticker("AAPL")).await.unwrap();
+    let quote = &response.results;
+    assert_eq!(quote.bid_price.unwrap().to_string(), "127.96");
+    assert_eq!(quote.ask_price.unwrap().to_string(), "127.98");
+    assert_eq!(quote.bid_exchange, 11);
+    assert_eq!(quote.ask_exchange, 19);
+    assert_eq!(quote.indicators, [604]);
+}
+
+#[tokio::test]
+async fn crypto_last_trade_and_forex_last_quote() {
+    let client = replay_client();
+
+    let response = client
+        .get_crypto_last_trade(&CryptoLastTradeRequest::new().from("BTC").to("USD"))
+        .await
+        .unwrap();
+    assert_eq!(response.symbol, "BTC-USD");
+    assert_eq!(response.last.price.to_string(), "16835.42");
+    assert_eq!(response.last.size, 0.006909);
+    assert_eq!(response.last.timestamp, datetime!(2020-11-16 21:08:05.027 UTC));
+
+    let response = client
+        .get_forex_last_quote(&ForexLastQuoteRequest::new().from("AUD").to("USD"))
+        .await
+        .unwrap();
+    assert_eq!(response.symbol, "AUD/USD");
+    assert_eq!(response.last.bid.to_string(), "0.7277");
+    assert_eq!(response.last.ask.to_string(), "0.7278");
+}
+
+#[tokio::test]
+async fn snapshot_ticker() {
+    let client = replay_client();
+
+    let response = client.get_snapshot_ticker(&SnapshotTickerRequest::new().ticker("AAPL")).await.unwrap();
+    let snapshot = &response.ticker;
+    assert_eq!(snapshot.ticker, "AAPL");
+    assert_eq!(snapshot.todays_change.to_string(), "-0.124");
+    assert_eq!(snapshot.day.as_ref().unwrap().close.to_string(), "20.506");
+    assert_eq!(snapshot.prev_day.as_ref().unwrap().volume, 292_738.0);
+    assert_eq!(snapshot.min.as_ref().unwrap().accumulated_volume, 37_216.0);
+    assert_eq!(snapshot.min.as_ref().unwrap().transactions, Some(1));
+    assert_eq!(snapshot.last_trade.as_ref().unwrap().size, 2416.0);
+    assert_eq!(snapshot.last_quote.as_ref().unwrap().ask_price.unwrap().to_string(), "20.6");
+}
+
+#[tokio::test]
+async fn snapshot_tickers_and_gainers() {
+    let client = replay_client();
+
+    let response = client
+        .get_snapshot_all_tickers(&SnapshotTickersRequest::new().tickers(["AAPL", "BCDA"]))
+        .await
+        .unwrap();
+    let tickers: Vec<_> = response.tickers.iter().map(|snapshot| snapshot.ticker.as_str()).collect();
+    assert_eq!(tickers, ["AAPL", "BCDA"]);
+
+    let request = SnapshotGainersLosersRequest::new().direction(Direction::Gainers);
+    let response = client.get_snapshot_gainers_losers(&request).await.unwrap();
+    assert_eq!(response.tickers[0].ticker, "BCDA");
+    assert_eq!(response.tickers[0].last_trade, None);
+}
+
+#[tokio::test]
+async fn universal_snapshot() {
+    let client = replay_client();
+    let tickers = ["AAPL", "O:AAPL230616C00150000", "I:SPX", "C:EURUSD", "X:BTCUSD", "XYZZ", "F:ESU3"];
+    let request = UniversalSnapshotRequest::new().tickers(tickers);
+
+    let response = client.get_universal_snapshot(&request).await.unwrap();
+    let returned: Vec<_> = response.results.iter().map(|snapshot| snapshot.ticker().unwrap()).collect();
+    assert_eq!(returned, tickers);
+
+    match &response.results[0] {
+        UniversalSnapshot::Stocks(stock) => {
+            assert_eq!(
+                stock.session.as_ref().unwrap().close.map(|close| close.to_string()),
+                Some("171.94".to_string())
+            );
+            assert_eq!(stock.last_quote.as_ref().unwrap().bid_exchange, Some(12));
+        }
+        other => panic!("expected a stock snapshot, got {:?}", other),
+    }
+    match &response.results[1] {
+        UniversalSnapshot::Options(option) => {
+            let details = option.details.as_ref().unwrap();
+            assert_eq!(details.contract_type, "call");
+            assert_eq!(details.expiration_date, Some(date!(2023 - 06 - 16)));
+            assert_eq!(option.underlying_asset.as_ref().unwrap().ticker, "AAPL");
+            assert!(option.greeks.as_ref().unwrap().delta > 0.5);
+        }
+        other => panic!("expected an option snapshot, got {:?}", other),
+    }
+    assert!(matches!(&response.results[2], UniversalSnapshot::Indices(index) if index.value == Some(4328.82)));
+    assert!(matches!(&response.results[3], UniversalSnapshot::FX(fx) if fx.last_quote.as_ref().unwrap().exchange == Some(48)));
+    assert!(matches!(&response.results[4], UniversalSnapshot::Crypto(crypto) if crypto.last_trade.as_ref().unwrap().size == 0.00418));
+    assert!(matches!(&response.results[5], UniversalSnapshot::Error(error) if error.error == "NOT_FOUND"));
+    assert!(matches!(&response.results[6], UniversalSnapshot::Unknown(_)));
+
+    let json = serde_json::to_string(&response).unwrap();
+    assert_eq!(serde_json::from_str::<UniversalSnapshotResponse>(&json).unwrap(), response);
+}
+
+#[tokio::test]
+async fn splits() {
+    let client = replay_client();
+    let request = SplitRequest::new()
+        .ticker("AAPL")
+        .execution_date(date!(2000 - 01 - 01)..)
+        .order(Order::Desc)
+        .sort(SplitSort::Execution_Date);
+
+    let response = client.get_splits(&request).await.unwrap();
+    assert!(response.next_url().is_none());
+
+    let dates: Vec<_> = response.results.iter().map(|split| split.execution_date).collect();
+    assert_eq!(dates, [date!(2020 - 08 - 31), date!(2014 - 06 - 09), date!(2005 - 02 - 28)]);
+    assert_eq!(response.results[0].split_from, 1.0);
+    assert_eq!(response.results[0].split_to, 4.0);
+    assert_eq!(response.results[0].ratio(), 4.0);
+}
+
+#[tokio::test]
+async fn financials() {
+    let client = replay_client();
+    let request = FinancialsRequest::new()
+        .ticker("AAPL")
+        .timeframe(FinancialsTimeframe::Quarterly)
+        .include_sources(true)
+        .limit(1);
+
+    let response = client.get_financials(&request).await.unwrap();
+    assert!(response.next_url().is_some());
+
+    let filing = &response.results[0];
+    assert_eq!(filing.cik, "0000320193");
+    assert_eq!(filing.fiscal_period, "Q3");
+    assert_eq!(filing.end_date, Some(date!(2023 - 07 - 01)));
+
+    let income_statement = &filing.financials.income_statement;
+    let revenues = income_statement.revenues.as_ref().unwrap();
+    assert_eq!(revenues.label, "Revenues");
+    assert_eq!(revenues.unit, "USD");
+    assert_eq!(revenues.value, 81_797_000_000.0);
+    assert_eq!(income_statement.diluted_earnings_per_share.as_ref().unwrap().unit, "USD / shares");
+    assert_eq!(income_statement.gross_profit.as_ref().unwrap().value, 36_413_000_000.0);
+    assert_eq!(income_statement.other["research_and_development"].value, 7_442_000_000.0);
+    assert!(income_statement.cost_of_revenue.is_none());
+    assert_eq!(filing.financials.balance_sheet.assets.as_ref().unwrap().value, 335_038_000_000.0);
+
+    let net_cash_flow = filing.financials.cash_flow_statement.net_cash_flow.as_ref().unwrap();
+    assert_eq!(net_cash_flow.value, -1_594_000_000.0);
+    assert_eq!(
+        net_cash_flow.source.as_ref().and_then(|source| source.formula.as_deref()),
+        Some("NCFOA + NCFIA + NCFFA")
+    );
+    assert_eq!(net_cash_flow.derived_from.len(), 3);
+}
+
+#[tokio::test]
+async fn conditions() {
+    let client = replay_client();
+    let request = ConditionsRequest::new().asset_class(AssetClass::Stocks).limit(1000);
+
+    let conditions: Vec<_> = client.conditions_stream(&request, PaginationOptions::new()).try_collect().await.unwrap();
+    let ids: Vec<_> = conditions.iter().map(|condition| condition.id).collect();
+    assert_eq!(ids, [0, 1, 12, 37, 1]);
+    assert_eq!(conditions[1].data_types, [DataType::BBO, DataType::NBBO]);
+    assert!(conditions[1].update_rules.is_none());
+    assert_eq!(conditions[2].sip_mapping["CTA"], "T");
+}
+
+#[tokio::test]
+async fn reference_tables() {
+    let client = replay_client();
+
+    let tables = client.load_reference_tables(AssetClass::Stocks).await.unwrap();
+    assert_eq!(tables.ticker_type(&TickerType::CS).unwrap().description, "Common Stock");
+    assert_eq!(tables.exchange(12).unwrap().name, "Nasdaq");
+    assert_eq!(tables.exchange_by_mic("XNYS").unwrap().id, 10);
+    assert!(tables.exchange_by_mic("OOTC").is_none());
+    assert_eq!(tables.trade_condition(1).unwrap().name, "Acquisition");
+    assert_eq!(tables.quote_condition(1).unwrap().name, "Regular, Two-Sided Open");
+
+    let updates = tables.trade_updates(&[0, 12]);
+    assert!(updates.updates_volume);
+    assert!(!updates.updates_high_low);
+    assert!(tables.trade_updates(&[0]).updates_high_low);
+}
+
+#[tokio::test]
+async fn market_status() {
+    let client = replay_client();
+
+    let response = client.get_market_status(&MarketStatusRequest::new()).await.unwrap();
+    assert_eq!(response.market, MarketState::ExtendedHours);
+    assert!(response.after_hours);
+    assert!(!response.early_hours);
+    assert_eq!(response.exchanges.nyse, MarketState::ExtendedHours);
+    assert_eq!(response.exchanges.otc, MarketState::Closed);
+    assert_eq!(response.currencies.crypto, MarketState::Open);
+    assert_eq!(response.indices_groups["s_and_p"], MarketState::Closed);
+    assert_eq!(response.server_time, datetime!(2020-11-10 22:37:37 UTC));
+}
+
+#[tokio::test]
+async fn market_holidays() {
+    let client = replay_client();
+
+    let holidays = client.get_market_holidays(&MarketHolidaysRequest::new()).await.unwrap();
+    assert_eq!(holidays.len(), 5);
+    assert_eq!(holidays[0].date, date!(2020 - 11 - 26));
+    assert_eq!(holidays[0].status, HolidayStatus::Closed);
+    assert!(holidays[0].close.is_none());
+
+    let early_close = &holidays[2];
+    assert_eq!(early_close.exchange, "NYSE");
+    assert_eq!(early_close.status, HolidayStatus::EarlyClose);
+    assert_eq!(early_close.open, Some(datetime!(2020-11-27 14:30:00 UTC)));
+    assert_eq!(early_close.close, Some(datetime!(2020-11-27 18:00:00 UTC)));
+}
+
+#[tokio::test]
+async fn models_round_trip_through_serialize() {
+    let client = replay_client();
+    let request = DividendRequest::new().ticker("AAPL").limit(2);
+
+    let response = client.get_dividends(&request).await.unwrap();
+    let json = serde_json::to_string(&response).unwrap();
+    assert_eq!(serde_json::from_str::<DividendsResponse>(&json).unwrap(), response);
+}
+
+#[tokio::test]
+async fn unrecorded_request_fails() {
+    let client = replay_client();
+    let request = TickerDetailsRequest::new().ticker("MSFT");
+
+    let result = client.get_tickers_details(&request).await;
+    assert!(matches!(result, Err(PolygonError::Cassette(_))));
+}
+
+#[tokio::test]
+async fn record_then_replay() {
+    let dir = std::env::temp_dir().join(format!("polygon-cassettes-{}", std::process::id()));
+    let body = r#"{"request_id":"1","status":"OK","results":[]}"#;
+    let transport = MockTransport::new().with_response("/v3/reference/dividends", &[("ticker", "MSFT"), ("limit", "5")], body);
+    let request = DividendRequest::new().limit(5).ticker("MSFT");
+
+    let recording = PolygonClientBuilder::new().transport(transport).record(&dir).build().unwrap();
+    recording.get_dividends(&request).await.unwrap();
+
+    let replaying = PolygonClientBuilder::new().replay(&dir).build().unwrap();
+    let response = replaying.get_dividends(&request).await.unwrap();
+    assert!(response.results.is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
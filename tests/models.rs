@@ -0,0 +1,74 @@
+use polygon_io_client_rust::financials::FinancialsResponse;
+use polygon_io_client_rust::quotes::Quote;
+use polygon_io_client_rust::snapshots::TickerSnapshot;
+use polygon_io_client_rust::trades::Trade;
+use polygon_io_client_rust::universal_snapshot::{UniversalSnapshot, UniversalSnapshotResponse};
+
+#[test]
+fn one_sided_quote() {
+    let quote: Quote = serde_json::from_str(r#"{"bid_price":130.46,"bid_size":2,"sip_timestamp":1673274600000000000}"#).unwrap();
+    assert_eq!(quote.bid_price.unwrap().to_string(), "130.46");
+    assert_eq!(quote.ask_price, None);
+    assert_eq!(quote.ask_size, 0.0);
+}
+
+#[test]
+fn trade_without_size_is_rejected() {
+    let e = serde_json::from_str::<Trade>(r#"{"price":130.47,"sip_timestamp":1673274600000000000}"#).unwrap_err();
+    assert!(e.to_string().contains("missing field `size`"), "{}", e);
+}
+
+#[test]
+fn snapshot_before_the_first_trade_of_the_session() {
+    let empty: TickerSnapshot = serde_json::from_str(r#"{"ticker":"AAPL","min":{}}"#).unwrap();
+    let min = empty.min.unwrap();
+    assert_eq!(min.bar.close.to_string(), "0");
+    assert_eq!(min.timestamp, None);
+
+    let partial: TickerSnapshot = serde_json::from_str(r#"{"ticker":"AAPL","min":{"av":100,"c":130.2,"t":1673274600000}}"#).unwrap();
+    let min = partial.min.unwrap();
+    assert_eq!(min.accumulated_volume, 100.0);
+    assert_eq!(min.bar.close.to_string(), "130.2");
+    assert_eq!(min.bar.volume, 0.0);
+    assert!(min.timestamp.is_some());
+}
+
+#[test]
+fn malformed_universal_snapshot_does_not_fail_the_page() {
+    let body = r#"{"status":"OK","request_id":"1","results":[
+        {"type":"stocks","ticker":"AAPL","last_quote":{"bid":130.46,"bid_size":2}},
+        {"type":"stocks","ticker":"MSFT","last_trade":"not a trade"},
+        {"type":"crypto","ticker":"X:BTCUSD"}
+    ]}"#;
+
+    let response: UniversalSnapshotResponse = serde_json::from_str(body).unwrap();
+    assert_eq!(response.results.len(), 3);
+
+    match &response.results[0] {
+        UniversalSnapshot::Stocks(snapshot) => {
+            let quote = snapshot.last_quote.as_ref().unwrap();
+            assert_eq!(quote.bid.unwrap().to_string(), "130.46");
+            assert_eq!(quote.ask, None);
+        }
+        snapshot => panic!("unexpected snapshot {:?}", snapshot),
+    }
+    match &response.results[1] {
+        UniversalSnapshot::Malformed { value, error } => {
+            assert_eq!(value["last_trade"], "not a trade");
+            assert!(error.contains("invalid type"), "{}", error);
+        }
+        snapshot => panic!("unexpected snapshot {:?}", snapshot),
+    }
+    assert_eq!(response.results[1].ticker(), Some("MSFT"));
+    assert!(matches!(&response.results[2], UniversalSnapshot::Crypto(_)));
+}
+
+#[test]
+fn filing_without_financials() {
+    let body = r#"{"status":"OK","results":[{"cik":"0000320193","fiscal_period":"Q3"}]}"#;
+
+    let response: FinancialsResponse = serde_json::from_str(body).unwrap();
+    let financials = &response.results[0].financials;
+    assert!(financials.income_statement.revenues.is_none());
+    assert!(financials.balance_sheet.other.is_empty());
+}
@@ -0,0 +1,75 @@
+use polygon_io_client_rust::dividends::DividendRequest;
+use polygon_io_client_rust::error::PolygonError;
+use polygon_io_client_rust::polygon_client::{PolygonClient, PolygonClientBuilder};
+use polygon_io_client_rust::rate_limiter::RateLimit;
+use polygon_io_client_rust::transport::MockTransport;
+use std::time::Duration;
+use tokio::time::Instant;
+
+fn client(rate_limit: RateLimit) -> PolygonClient {
+    let transport = MockTransport::new().with_response("/v3/reference/dividends", &[], r#"{"status":"OK","request_id":"1","results":[]}"#);
+    PolygonClientBuilder::new()
+        .auth_key("key")
+        .transport(transport)
+        .rate_limit(rate_limit)
+        .build()
+        .unwrap()
+}
+
+/// Sends a request and returns how long it waited for the rate limit.
+async fn send(client: &PolygonClient) -> Result<Duration, PolygonError> {
+    let start = Instant::now();
+    client.get_dividends(&DividendRequest::new()).await?;
+    Ok(start.elapsed())
+}
+
+#[tokio::test(start_paused = true)]
+async fn burst_up_to_capacity_then_wait_for_refill() {
+    let client = client(RateLimit::per_second(2));
+
+    assert_eq!(send(&client).await.unwrap(), Duration::ZERO);
+    assert_eq!(send(&client).await.unwrap(), Duration::ZERO);
+    assert_eq!(send(&client).await.unwrap(), Duration::from_millis(500));
+    assert_eq!(send(&client).await.unwrap(), Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn refill_is_capped_at_capacity() {
+    let client = client(RateLimit::per_second(2));
+    send(&client).await.unwrap();
+    send(&client).await.unwrap();
+
+    tokio::time::advance(Duration::from_secs(10)).await;
+
+    assert_eq!(send(&client).await.unwrap(), Duration::ZERO);
+    assert_eq!(send(&client).await.unwrap(), Duration::ZERO);
+    assert_eq!(send(&client).await.unwrap(), Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_fast_returns_the_wait() {
+    let client = client(RateLimit::per_minute(1).fail_fast());
+    send(&client).await.unwrap();
+
+    tokio::time::advance(Duration::from_secs(15)).await;
+
+    match send(&client).await {
+        Err(e @ PolygonError::LocalRateLimited { retry_after }) => {
+            assert_eq!(retry_after, Duration::from_secs(45));
+            assert_eq!(e.status(), None);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    tokio::time::advance(Duration::from_secs(45)).await;
+    assert_eq!(send(&client).await.unwrap(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn clones_share_the_limit() {
+    let client = client(RateLimit::per_second(1));
+    let clone = client.clone();
+
+    assert_eq!(send(&client).await.unwrap(), Duration::ZERO);
+    assert_eq!(send(&clone).await.unwrap(), Duration::from_secs(1));
+}
@@ -0,0 +1,109 @@
+use polygon_io_client_rust::common::{AssetClass, Locale, TickerType};
+use polygon_io_client_rust::conditions::{Condition, DataType, UpdateRule, UpdateRules};
+use polygon_io_client_rust::exchanges::Exchange;
+use polygon_io_client_rust::reference_tables::ReferenceTables;
+use polygon_io_client_rust::ticker_types::TickerTypeResult;
+
+fn condition(id: u32, name: &str, data_types: &[DataType], consolidated: Option<UpdateRule>) -> Condition {
+    Condition {
+        id,
+        r#type: String::new(),
+        name: name.to_string(),
+        abbreviation: None,
+        description: None,
+        asset_class: AssetClass::Stocks,
+        data_types: data_types.to_vec(),
+        exchange: None,
+        legacy: false,
+        sip_mapping: Default::default(),
+        update_rules: consolidated.map(|consolidated| UpdateRules {
+            consolidated,
+            market_center: consolidated,
+        }),
+    }
+}
+
+fn rule(updates_high_low: bool, updates_open_close: bool, updates_volume: bool) -> UpdateRule {
+    UpdateRule {
+        updates_high_low,
+        updates_open_close,
+        updates_volume,
+    }
+}
+
+fn exchange(id: u32, name: &str, mic: Option<&str>) -> Exchange {
+    Exchange {
+        id,
+        r#type: "exchange".to_string(),
+        asset_class: AssetClass::Stocks,
+        locale: Locale::US,
+        name: name.to_string(),
+        acronym: None,
+        mic: mic.map(str::to_string),
+        operating_mic: String::new(),
+        participant_id: None,
+        url: None,
+    }
+}
+
+fn tables() -> ReferenceTables {
+    let ticker_types = [TickerTypeResult {
+        asset_class: AssetClass::Stocks,
+        code: TickerType::ETF,
+        description: "Exchange Traded Fund".to_string(),
+        locale: Locale::US,
+    }];
+    let exchanges = [
+        exchange(10, "New York Stock Exchange", Some("XNYS")),
+        exchange(62, "OTC Equity Security", None),
+    ];
+    let conditions = [
+        condition(0, "Regular Trade", &[DataType::Trade], Some(rule(true, true, true))),
+        condition(1, "Acquisition", &[DataType::Trade], Some(rule(true, true, true))),
+        condition(1, "Regular, Two-Sided Open", &[DataType::BBO, DataType::NBBO], None),
+        condition(12, "Form T", &[DataType::Trade], Some(rule(false, true, true))),
+        condition(15, "Market Center Official Open", &[DataType::Trade], Some(rule(true, false, false))),
+        condition(52, "Contingent Trade", &[DataType::Trade], None),
+    ];
+    ReferenceTables::new(ticker_types, exchanges, conditions)
+}
+
+#[test]
+fn trade_and_quote_conditions_with_the_same_id_are_kept_apart() {
+    let tables = tables();
+    assert_eq!(tables.trade_condition(1).unwrap().name, "Acquisition");
+    assert_eq!(tables.quote_condition(1).unwrap().name, "Regular, Two-Sided Open");
+    assert!(tables.quote_condition(12).is_none());
+    assert!(tables.trade_condition(99).is_none());
+}
+
+#[test]
+fn exchanges_by_id_and_mic() {
+    let tables = tables();
+    assert_eq!(tables.exchange(10).unwrap().name, "New York Stock Exchange");
+    assert_eq!(tables.exchange_by_mic("XNYS").unwrap().id, 10);
+    assert!(tables.exchange_by_mic("XNAS").is_none());
+    assert_eq!(tables.exchange(62).unwrap().name, "OTC Equity Security");
+}
+
+#[test]
+fn ticker_types_by_code() {
+    let tables = tables();
+    assert_eq!(tables.ticker_type(&TickerType::ETF).unwrap().description, "Exchange Traded Fund");
+    assert!(tables.ticker_type(&TickerType::CS).is_none());
+}
+
+#[test]
+fn trade_updates_allow_only_what_every_condition_allows() {
+    let tables = tables();
+    assert_eq!(tables.trade_updates(&[]), rule(true, true, true));
+    assert_eq!(tables.trade_updates(&[0]), rule(true, true, true));
+    assert_eq!(tables.trade_updates(&[0, 12]), rule(false, true, true));
+    assert_eq!(tables.trade_updates(&[12, 15]), rule(false, false, false));
+}
+
+#[test]
+fn trade_updates_ignore_unknown_conditions_and_conditions_without_rules() {
+    let tables = tables();
+    assert_eq!(tables.trade_updates(&[12, 52, 99]), rule(false, true, true));
+}
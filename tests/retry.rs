@@ -0,0 +1,102 @@
+use polygon_io_client_rust::dividends::DividendRequest;
+use polygon_io_client_rust::error::PolygonError;
+use polygon_io_client_rust::polygon_client::{PolygonClient, PolygonClientBuilder};
+use polygon_io_client_rust::retry::RetryPolicy;
+use polygon_io_client_rust::transport::{HttpResponse, MockTransport};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const PATH: &str = "/v3/reference/dividends";
+const OK: &str = r#"{"status":"OK","request_id":"1","results":[]}"#;
+
+fn client(transport: &MockTransport, retry_policy: RetryPolicy) -> PolygonClient {
+    PolygonClientBuilder::new()
+        .auth_key("key")
+        .transport(transport.clone())
+        .retry_policy(retry_policy)
+        .build()
+        .unwrap()
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new().base_backoff(Duration::from_secs(1)).jitter(false)
+}
+
+#[tokio::test(start_paused = true)]
+async fn gateway_error_then_success() {
+    let transport = MockTransport::new()
+        .with_status(PATH, &[], 502, "Bad Gateway")
+        .with_response(PATH, &[], OK);
+    let client = client(&transport, policy());
+
+    let start = Instant::now();
+    client.get_dividends(&DividendRequest::new()).await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert_eq!(transport.sent_requests().len(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn unauthorized_is_not_retried() {
+    for status in [401, 403] {
+        let transport = MockTransport::new().with_status(PATH, &[], status, r#"{"status":"NOT_AUTHORIZED"}"#);
+        let client = client(&transport, policy().max_attempts(5));
+
+        let result = client.get_dividends(&DividendRequest::new()).await;
+        assert!(matches!(result, Err(PolygonError::Unauthorized { .. })));
+        assert_eq!(transport.sent_requests().len(), 1);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_after_takes_priority_over_backoff() {
+    let mut rate_limited = HttpResponse::new(429, "");
+    rate_limited.retry_after = Some(Duration::from_secs(7));
+    let transport = MockTransport::new()
+        .with_http_response(PATH, &[], rate_limited)
+        .with_response(PATH, &[], OK);
+    let client = client(&transport, policy());
+
+    let start = Instant::now();
+    client.get_dividends(&DividendRequest::new()).await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(7));
+    assert_eq!(transport.sent_requests().len(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn backoff_doubles_up_to_max() {
+    let transport = MockTransport::new()
+        .with_status(PATH, &[], 503, "")
+        .with_status(PATH, &[], 503, "")
+        .with_status(PATH, &[], 503, "")
+        .with_response(PATH, &[], OK);
+    let client = client(&transport, policy().max_attempts(4).max_backoff(Duration::from_secs(3)));
+
+    let start = Instant::now();
+    client.get_dividends(&DividendRequest::new()).await.unwrap();
+    // 1s, 2s, then 4s capped at 3s
+    assert_eq!(start.elapsed(), Duration::from_secs(6));
+    assert_eq!(transport.sent_requests().len(), 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn gives_up_after_max_attempts_with_the_last_error() {
+    let transport = MockTransport::new()
+        .with_status(PATH, &[], 502, "")
+        .with_status(PATH, &[], 503, "")
+        .with_status(PATH, &[], 504, "");
+    let client = client(&transport, policy().max_attempts(3));
+
+    let result = client.get_dividends(&DividendRequest::new()).await;
+    assert!(matches!(result, Err(PolygonError::Http { status: 504, .. })));
+    assert_eq!(transport.sent_requests().len(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn other_errors_are_not_retried() {
+    let transport = MockTransport::new().with_status(PATH, &[], 500, "");
+    let client = client(&transport, policy());
+
+    let result = client.get_dividends(&DividendRequest::new()).await;
+    assert!(matches!(result, Err(PolygonError::Http { status: 500, .. })));
+    assert_eq!(transport.sent_requests().len(), 1);
+}
@@ -0,0 +1,56 @@
+use polygon_io_client_rust::dividends::DividendRequest;
+use polygon_io_client_rust::polygon_client::{PolygonClient, PolygonClientBuilder};
+use polygon_io_client_rust::transport::{HttpRequest, MockTransport};
+
+fn request(query: &[(&str, &str)]) -> HttpRequest {
+    HttpRequest {
+        api_url: "https://api.polygon.io".to_string(),
+        path: "/v3/reference/dividends".to_string(),
+        query: query.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+        auth_key: "key".to_string(),
+    }
+}
+
+fn client(transport: &MockTransport) -> PolygonClient {
+    PolygonClientBuilder::new().auth_key("key").transport(transport.clone()).build().unwrap()
+}
+
+#[test]
+fn request_key_ignores_parameter_order() {
+    let a = request(&[("ticker", "AAPL"), ("limit", "5"), ("order", "desc")]);
+    let b = request(&[("order", "desc"), ("ticker", "AAPL"), ("limit", "5")]);
+    assert_eq!(a.key(), b.key());
+    assert_eq!(a.key(), "/v3/reference/dividends?limit=5&order=desc&ticker=AAPL");
+}
+
+#[test]
+fn request_key_excludes_api_key() {
+    let with_key = request(&[("ticker", "AAPL"), ("apiKey", "secret")]);
+    assert_eq!(with_key.key(), "/v3/reference/dividends?ticker=AAPL");
+    assert_eq!(with_key.key(), request(&[("ticker", "AAPL")]).key());
+}
+
+#[test]
+fn request_key_encodes_names_and_values() {
+    let one = request(&[("search", "a&b=c")]);
+    let two = request(&[("search", "a"), ("b", "c")]);
+    assert_ne!(one.key(), two.key());
+    assert_eq!(one.key(), "/v3/reference/dividends?search=a%26b%3Dc");
+}
+
+#[tokio::test]
+async fn mock_returns_responses_in_order_then_repeats_the_last() {
+    let transport = MockTransport::new()
+        .with_response("/v3/reference/dividends", &[], r#"{"status":"OK","request_id":"1","results":[]}"#)
+        .with_response("/v3/reference/dividends", &[], r#"{"status":"OK","request_id":"2","results":[]}"#);
+    let client = client(&transport);
+    let request = DividendRequest::new();
+
+    let ids: Vec<String> = vec![
+        client.get_dividends(&request).await.unwrap().request_id,
+        client.get_dividends(&request).await.unwrap().request_id,
+        client.get_dividends(&request).await.unwrap().request_id,
+    ];
+    assert_eq!(ids, ["1", "2", "2"]);
+    assert_eq!(transport.sent_requests(), vec!["/v3/reference/dividends"; 3]);
+}
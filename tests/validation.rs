@@ -0,0 +1,89 @@
+use polygon_io_client_rust::aggregates::AggregatesRequest;
+use polygon_io_client_rust::dividends::DividendRequest;
+use polygon_io_client_rust::error::PolygonError;
+use polygon_io_client_rust::filter::RangeFilter;
+use polygon_io_client_rust::polygon_client::{PolygonClient, PolygonClientBuilder};
+use polygon_io_client_rust::ticker_details::TickerDetailsRequest;
+use polygon_io_client_rust::tickers::TickersRequest;
+use polygon_io_client_rust::transport::MockTransport;
+use polygon_io_client_rust::universal_snapshot::UniversalSnapshotRequest;
+use polygon_io_client_rust::validation::ValidationError;
+use time::macros::date;
+
+fn client() -> PolygonClient {
+    PolygonClientBuilder::new().transport(MockTransport::new()).build().unwrap()
+}
+
+fn validation_error<T: std::fmt::Debug>(result: Result<T, PolygonError>) -> ValidationError {
+    match result {
+        Err(PolygonError::Validation(e)) => e,
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn missing_path_parameter() {
+    let result = client().get_tickers_details(&TickerDetailsRequest::new()).await;
+
+    assert_eq!(validation_error(result), ValidationError::MissingPathParameter("ticker"));
+}
+
+#[tokio::test]
+async fn limit_out_of_bounds() {
+    let result = client().get_tickers(&TickersRequest::new().limit(0)).await;
+    assert!(matches!(validation_error(result), ValidationError::InvalidLimit { max: 1000, .. }));
+
+    let result = client().get_tickers(&TickersRequest::new().limit(1001)).await;
+    assert!(matches!(validation_error(result), ValidationError::InvalidLimit { max: 1000, .. }));
+}
+
+#[tokio::test]
+async fn inverted_range() {
+    let request = DividendRequest::new().ex_dividend_date(RangeFilter::between(date!(2023 - 06 - 30), date!(2023 - 01 - 01)));
+    let result = client().get_dividends(&request).await;
+
+    assert!(matches!(validation_error(result), ValidationError::InvertedRange { .. }));
+}
+
+#[tokio::test]
+async fn exact_value_and_bound_are_exclusive() {
+    let mut request = DividendRequest::new().ticker("AAPL");
+    request.parameters.insert("ticker.gt".to_string(), "A".to_string());
+    let result = client().get_dividends(&request).await;
+
+    assert_eq!(
+        validation_error(result),
+        ValidationError::MutuallyExclusive("ticker".to_string(), "ticker.gt".to_string())
+    );
+}
+
+#[tokio::test]
+async fn invalid_value() {
+    let mut request = DividendRequest::new();
+    request.parameters.insert("cash_amount.gte".to_string(), "abc".to_string());
+    let result = client().get_dividends(&request).await;
+
+    assert!(matches!(validation_error(result), ValidationError::InvalidValue { .. }));
+}
+
+#[tokio::test]
+async fn aggregates_require_a_date_range() {
+    let request = AggregatesRequest::new().ticker("AAPL").from(date!(2023 - 01 - 09));
+    let result = client().get_aggregates(&request).await;
+    assert_eq!(validation_error(result), ValidationError::MissingPathParameter("to"));
+
+    let request = request.to(date!(2023 - 01 - 01));
+    let result = client().get_aggregates(&request).await;
+    assert!(matches!(validation_error(result), ValidationError::InvertedRange { .. }));
+}
+
+#[tokio::test]
+async fn ticker_list_and_ticker_filter_are_exclusive() {
+    let request = UniversalSnapshotRequest::new().tickers(["AAPL", "MSFT"]).ticker("A".."B");
+    let result = client().get_universal_snapshot(&request).await;
+
+    assert_eq!(
+        validation_error(result),
+        ValidationError::MutuallyExclusive("ticker.gte".to_string(), "ticker.any_of".to_string())
+    );
+}